@@ -0,0 +1,202 @@
+//! Build a row of resizable columns in one go.
+//!
+//! ```no_run
+//! # use iced::widget::text;
+//! # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)) }
+//! # let widths = vec![100.0, 200.0, 150.0];
+//! use iced_divider::columns::columns;
+//!
+//! let table: iced::Element<Message> = columns(widths)
+//!     .children([text("a"), text("b"), text("c")])
+//!     .min_width(40.0)
+//!     .spacing(2.0)
+//!     .on_resize(Message::Resized)
+//!     .into();
+//! ```
+use iced::widget::{container, row};
+use iced::Element;
+
+use crate::divider::{divider_horizontal, Divider, Id, Role};
+use crate::measure::measured;
+use crate::resize::keep_min_sizes;
+
+/// Creates [`Columns`] with the given widths.
+pub fn columns<'a, Message>(widths: Vec<f32>) -> Columns<'a, Message>
+where
+    Message: Clone,
+{
+    Columns::new(widths)
+}
+
+/// A row of containers with a [`Divider`] to resize them.
+///
+/// The message of [`Columns::on_resize`] is the same as the one of
+/// the [`Divider`], the index of the column and its new width.
+#[allow(missing_debug_implementations)]
+pub struct Columns<'a, Message> {
+    id: Option<Id>,
+    widths: Vec<f32>,
+    children: Vec<Element<'a, Message>>,
+    on_resize: Option<Box<dyn Fn((usize, f32)) -> Message + 'a>>,
+    min_width: f32,
+    collapse_below: Option<f32>,
+    spacing: f32,
+    handle_width: f32,
+    handle_height: f32,
+}
+
+impl<'a, Message> Columns<'a, Message>
+where
+    Message: Clone,
+{
+    /// Creates new [`Columns`] with the given widths.
+    pub fn new(widths: Vec<f32>) -> Self {
+        Columns {
+            id: None,
+            widths,
+            children: vec![],
+            on_resize: None,
+            min_width: 0.0,
+            collapse_below: None,
+            spacing: 0.0,
+            handle_width: 4.0,
+            handle_height: <Divider<'a, Message>>::DEFAULT_HEIGHT,
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Divider`], to query the
+    /// [`natural_sizes`](crate::operation::natural_sizes) of the columns.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the contents of the columns, one for each width.
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = impl Into<Element<'a, Message>>>,
+    ) -> Self {
+        self.children = children.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the message produced when a column is resized.
+    /// Without it, the columns cannot be resized.
+    pub fn on_resize(mut self, on_resize: impl Fn((usize, f32)) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the width no column can be resized below.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the width below which a resized column snaps to zero,
+    /// instead of stopping at the minimum width.
+    pub fn collapse_below(mut self, collapse_below: f32) -> Self {
+        self.collapse_below = Some(collapse_below);
+        self
+    }
+
+    /// Sets the space between the columns.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the handles.
+    pub fn handle_width(mut self, handle_width: f32) -> Self {
+        self.handle_width = handle_width;
+        self
+    }
+
+    /// Sets the height of the handles.
+    pub fn handle_height(mut self, handle_height: f32) -> Self {
+        self.handle_height = handle_height;
+        self
+    }
+}
+
+impl<'a, Message> From<Columns<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(columns: Columns<'a, Message>) -> Self {
+        let Columns {
+            id,
+            widths,
+            children,
+            on_resize,
+            min_width,
+            collapse_below,
+            spacing,
+            handle_width,
+            handle_height,
+        } = columns;
+
+        let containers = children.into_iter()
+            .zip(widths.iter())
+            .map(|(content, width)| container(measured(content)).width(*width).into());
+
+        let content = row(containers).spacing(spacing);
+
+        let Some(on_resize) = on_resize else {
+            return content.into();
+        };
+
+        let gaps = vec![spacing; widths.len().saturating_sub(1)];
+        let limits = widths.clone();
+
+        let is_collapsible = collapse_below.is_some();
+
+        let mut divider = divider_horizontal(
+            widths,
+            handle_width,
+            handle_height,
+            move |(index, value)| {
+                on_resize(keep_min_width(&limits, min_width, is_collapsible, (index, value)))
+            })
+            .gaps(gaps)
+            .role(Role::Column)
+            .content(content);
+
+        if let Some(collapse_below) = collapse_below {
+            divider = divider.collapse_below(collapse_below);
+        }
+
+        match id {
+            Some(id) => divider.id(id).into(),
+            None => divider.into(),
+        }
+    }
+}
+
+/// Keeps the resized column and the one after it at least min_width wide,
+/// unless they are collapsed to zero.
+fn keep_min_width(
+    widths: &[f32],
+    min_width: f32,
+    is_collapsible: bool,
+    (index, value): (usize, f32),
+) -> (usize, f32) {
+    let total = widths.get(index+1).map(|next| widths[index] + next);
+
+    if is_collapsible && (value <= 0.0 || Some(value) == total) {
+        return (index, value);
+    }
+    keep_min_sizes(widths, &vec![min_width; widths.len()], (index, value))
+}
+
+#[test]
+fn test_keep_min_width() {
+    let widths = [100.0, 200.0, 150.0];
+
+    assert_eq!(keep_min_width(&widths, 40.0, false, (0, 10.0)), (0, 40.0));
+    assert_eq!(keep_min_width(&widths, 40.0, false, (0, 290.0)), (0, 260.0));
+    assert_eq!(keep_min_width(&widths, 40.0, false, (1, 250.0)), (1, 250.0));
+    assert_eq!(keep_min_width(&widths, 40.0, false, (2, 500.0)), (2, 500.0));
+    assert_eq!(keep_min_width(&widths, 40.0, true, (0, 0.0)), (0, 0.0));
+    assert_eq!(keep_min_width(&widths, 40.0, true, (0, 300.0)), (0, 300.0));
+}
@@ -1,17 +1,32 @@
 //! Display an interactive selector of a single value from a range of values to resize containers.
 use iced::border::{Border, Radius};
 use iced::event::{self, Event};
+use iced::futures::channel::mpsc;
 use iced::advanced::layout;
-use iced::{mouse, Background};
+use iced::advanced::overlay;
+use iced::{alignment, keyboard, mouse, Background};
 use iced::advanced::renderer;
+use iced::advanced::text::{self, Paragraph, Text};
+use iced::time::Instant;
 use iced::touch;
+use iced::window;
 use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::Operation;
+use iced::widget::scrollable::AbsoluteOffset;
 use iced::{
     self, Color, Element, Length, 
-    Rectangle, Size, Theme,
+    Point, Rectangle, Size, Theme, Vector,
 };
 use iced::advanced::{Clipboard, Layout, Shell, Widget};
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::measure::{self, measured};
+use crate::resize;
+
+pub use iced::advanced::widget::Id;
+
 /// Dividers let users resize an by moving the divider handle..
 ///
 /// # Example
@@ -83,18 +98,18 @@ use iced::advanced::{Clipboard, Layout, Shell, Widget};
 ///     }
 /// }
 /// ```
-pub fn divider_horizontal<'a, Message, Theme>(
+pub fn divider_horizontal<'a, Message, Theme, Renderer, F>(
     widths: Vec<f32>,
     handle_width: f32,
     handle_height: f32,
-    on_change: impl Fn((usize, f32)) -> Message + 'a,
-) -> Divider<'a, Message, Theme>
+    on_change: F,
+) -> Divider<'a, Message, Theme, Renderer, F>
 where
     Message: Clone,
     Theme: Catalog + 'a,
+    F: Fn((usize, f32)) -> Message + 'a,
 {
-    let mut handle_offsets = vec![-handle_width/2.0; widths.len()-1];
-        handle_offsets.extend([-handle_width]);
+    let handle_offsets = default_offsets(widths.len(), handle_width);
     Divider::new(
             widths, 
             handle_width, 
@@ -104,20 +119,19 @@ where
             on_change)
 }
 
-pub fn divider_vertical<'a, Message, Theme>(
+pub fn divider_vertical<'a, Message, Theme, Renderer, F>(
     heights: Vec<f32>,
     handle_width: f32,
     handle_height: f32,
-    on_change: impl Fn((usize, f32)) -> Message + 'a,
-) -> Divider<'a, Message, Theme>
+    on_change: F,
+) -> Divider<'a, Message, Theme, Renderer, F>
 where
     Message: Clone,
     Theme: Catalog + 'a,
+    F: Fn((usize, f32)) -> Message + 'a,
 {
     let widths = heights;
-    let mut handle_offsets = vec![-handle_height/2.0; widths.len()-1];
-        // last offset pulled in to keep in bounds
-        handle_offsets.extend([-handle_height]);
+    let handle_offsets = default_offsets(widths.len(), handle_height);
         
     Divider::new(
             widths, 
@@ -128,35 +142,255 @@ where
             on_change)
 }
 
+/// Creates a horizontal [`Divider`] with the common defaults, handles 4.0
+/// wide and [`Divider::DEFAULT_HEIGHT`] high with the primary style.
+pub fn simple<'a, Message, F>(
+    widths: Vec<f32>,
+    on_change: F,
+) -> Divider<'a, Message, iced::Theme, iced::Renderer, F>
+where
+    Message: Clone,
+    F: Fn((usize, f32)) -> Message + 'a,
+{
+    divider_horizontal(widths, 4.0, <Divider<'a, Message>>::DEFAULT_HEIGHT, on_change)
+}
+
+/// Creates a row of containers with a [`Divider`] drawn over it to resize them.
+///
+/// Each item is the content of a container and its width, the handles are
+/// [`Divider::DEFAULT_HEIGHT`] high. See also the [`resizable_row!`](crate::resizable_row) macro.
+pub fn resizable_row<'a, Message>(
+    items: Vec<(Element<'a, Message>, f32)>,
+    on_change: impl Fn((usize, f32)) -> Message + 'a,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    let widths: Vec<f32> = items.iter().map(|(_, width)| *width).collect();
+
+    let containers = items.into_iter()
+        .map(|(content, width)| iced::widget::container(measured(content)).width(width).into());
+
+    let div = divider_horizontal(
+        widths,
+        4.0,
+        <Divider<'a, Message>>::DEFAULT_HEIGHT,
+        on_change);
+
+    div.content(iced::widget::row(containers))
+        .content_minimums(true)
+        .into()
+}
+
+/// Places dividers over the content in a stack.
+///
+/// The content must come first so the dividers are drawn on top of it
+/// and receive the events before it. Outside of their handles the dividers
+/// leave the cursor and the presses to the content.
+pub fn with_dividers<'a, Message>(
+    content: Element<'a, Message>,
+    dividers: Vec<Element<'a, Message>>,
+) -> Element<'a, Message>
+where
+    Message: 'a,
+{
+    let mut layers = vec![content];
+    layers.extend(dividers);
+
+    iced::widget::stack(layers).into()
+}
+
+/// Maps the index of the changes to the key of the container, one key for
+/// each width, so the messages carry identifiers that survive reordering.
+///
+/// ```no_run
+/// # #[derive(Debug, Clone, PartialEq)] enum Column { Name, Size }
+/// # #[derive(Debug, Clone)] enum Message { Resized((Column, f32)) }
+/// # let widths = vec![200.0, 100.0];
+/// use iced_divider::divider::{divider_horizontal, keyed};
+///
+/// let divider = divider_horizontal(
+///     widths,
+///     4.0,
+///     21.0,
+///     keyed(vec![Column::Name, Column::Size], Message::Resized));
+/// # let _: iced_divider::divider::Divider<'_, Message, iced::Theme, iced::Renderer, _> = divider;
+/// ```
+///
+/// # Panics
+/// Panics if a change has an index without a key.
+pub fn keyed<'a, K, Message>(
+    keys: Vec<K>,
+    on_change: impl Fn((K, f32)) -> Message + 'a,
+) -> impl Fn((usize, f32)) -> Message + 'a
+where
+    K: Clone + PartialEq + 'a,
+{
+    move |(index, value)| on_change((keys[index].clone(), value))
+}
+
+/// Creates a horizontal [`Divider`] publishing the key of the container
+/// instead of its index, see [`keyed`]. The keys are parallel to the widths,
+/// so they move along when containers are inserted or removed.
+pub fn divider_horizontal_keyed<'a, K, Message, Theme, Renderer>(
+    keys: Vec<K>,
+    widths: Vec<f32>,
+    handle_width: f32,
+    handle_height: f32,
+    on_change: impl Fn((K, f32)) -> Message + 'a,
+) -> Divider<'a, Message, Theme, Renderer, impl Fn((usize, f32)) -> Message + 'a>
+where
+    K: Clone + PartialEq + 'a,
+    Message: Clone,
+    Theme: Catalog + 'a,
+{
+    debug_assert_eq!(keys.len(), widths.len(), "one key for each width");
+    divider_horizontal(widths, handle_width, handle_height, keyed(keys, on_change))
+}
+
+/// Creates a vertical [`Divider`] publishing the key of the container
+/// instead of its index, see [`divider_horizontal_keyed`].
+pub fn divider_vertical_keyed<'a, K, Message, Theme, Renderer>(
+    keys: Vec<K>,
+    heights: Vec<f32>,
+    handle_width: f32,
+    handle_height: f32,
+    on_change: impl Fn((K, f32)) -> Message + 'a,
+) -> Divider<'a, Message, Theme, Renderer, impl Fn((usize, f32)) -> Message + 'a>
+where
+    K: Clone + PartialEq + 'a,
+    Message: Clone,
+    Theme: Catalog + 'a,
+{
+    debug_assert_eq!(keys.len(), heights.len(), "one key for each height");
+    divider_vertical(heights, handle_width, handle_height, keyed(keys, on_change))
+}
+
+/// Creates a row of resizable containers, see [`divider::resizable_row`](crate::divider::resizable_row).
+///
+/// ```no_run
+/// # use iced::widget::text;
+/// # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)) }
+/// # let widths = [100.0, 200.0, 150.0];
+/// let row: iced::Element<Message> = iced_divider::resizable_row![
+///     text("a") => widths[0],
+///     text("b") => widths[1],
+///     text("c") => widths[2];
+///     Message::Resized
+/// ];
+/// ```
+#[macro_export]
+macro_rules! resizable_row {
+    ($($content:expr => $width:expr),+ $(,)? ; $on_change:expr) => {
+        $crate::divider::resizable_row(
+            vec![$(($content.into(), $width)),+],
+            $on_change,
+        )
+    };
+}
+
+/// The boxed on_change function of a [`Divider`], the default when its type isn't named.
+pub type OnChange<'a, Message> = Box<dyn Fn((usize, f32)) -> Message + 'a>;
 
 #[allow(missing_debug_implementations)]
-pub struct Divider<'a, Message, Theme = iced::Theme>
+pub struct Divider<
+    'a, 
+    Message, 
+    Theme = iced::Theme, 
+    Renderer = iced::Renderer, 
+    F = OnChange<'a, Message>,
+>
 where
     Theme: Catalog,
 {
+    id: Option<Id>,
+    content: Option<Element<'a, Message, Theme, Renderer>>,
     widths: Vec<f32>,
     handle_width: f32,
     handle_height: f32,
-    on_change: Box<dyn Fn((usize, f32)) -> Message + 'a>,
+    on_change: F,
     on_release: Option<Message>,
+    on_changes: Option<Box<dyn Fn(Vec<(usize, f32)>) -> Message + 'a>>,
+    on_release_widths: Option<Box<dyn Fn(Vec<f32>) -> Message + 'a>>,
+    on_release_handle: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_divider_event: Option<Box<dyn Fn(DividerEvent) -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(DragPhase, usize, f32) -> Message + 'a>>,
+    sender: Option<mpsc::UnboundedSender<DividerChange>>,
+    drag_sessions: Option<mpsc::UnboundedSender<DragSession>>,
+    on_hover: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_hover_exit: Option<Message>,
+    on_right_click: Option<Box<dyn Fn(usize, Point) -> Message + 'a>>,
+    context_menu: Option<Box<dyn Fn(usize) -> Vec<(String, Message)> + 'a>>,
+    on_autofit: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_long_press: Option<Box<dyn Fn(usize, Point) -> Message + 'a>>,
+    on_auto_scroll: Option<Box<dyn Fn(AbsoluteOffset) -> Message + 'a>>,
+    long_press_duration: Duration,
     width: Length,
     height: Length,
     handle_offsets: Vec<f32>,
     include_last_handle: bool,
+    min_total: Option<f32>,
+    max_total: Option<f32>,
+    shaping: Option<Box<dyn Fn(f32) -> f32 + 'a>>,
+    steps: Vec<f32>,
+    snap_hysteresis: f32,
+    step_labels: Vec<(f32, String)>,
+    step: Option<f32>,
+    min_delta: f32,
+    ghost: bool,
+    guides: bool,
+    crosshair: bool,
+    ticks: Option<TickMarks>,
+    marks: Vec<(f32, String)>,
+    keyboard_step: f32,
+    wheel_step: Option<f32>,
+    invert_wheel: bool,
+    transition: Option<Duration>,
+    fade_out: Option<(Duration, f32)>,
+    disabled: bool,
+    clamp: bool,
+    content_minimums: bool,
+    track_only: bool,
+    grab_full_length: bool,
+    mirror: bool,
+    clamp_to_viewport: bool,
+    resize_strategy: Option<resize::Strategy>,
+    on_out_of_range: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_limit: Option<Box<dyn Fn(usize, Limit) -> Message + 'a>>,
+    on_hidden: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_snap: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_swap: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_step: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    collapse_below: Option<f32>,
+    default_widths: Option<Vec<f32>>,
+    weights: Option<Vec<f32>>,
+    on_redistribute: Option<(resize::Redistribution, Box<dyn Fn(Vec<f32>) -> Message + 'a>)>,
+    shrink_priority: Vec<u8>,
+    frozen: usize,
+    scroll_offset: f32,
+    integer: bool,
+    emit_ratios: bool,
+    units: Option<(Box<dyn Fn(f32) -> f32 + 'a>, Box<dyn Fn(f32) -> f32 + 'a>)>,
+    gaps: Vec<f32>,
     direction: Direction,
     class: Theme::Class<'a>,
+    indexed_style: Option<Box<dyn Fn(&Theme, Status, usize) -> Style + 'a>>,
 }
 
-impl<'a, Message, Theme> Divider<'a, Message, Theme>
+impl<'a, Message, Theme, Renderer, F> Divider<'a, Message, Theme, Renderer, F>
 where
     Message: Clone,
     Theme: Catalog,
+    F: Fn((usize, f32)) -> Message,
 {
     /// The default height of a [`Divider`].
     pub const DEFAULT_HEIGHT: f32 = 21.0;
 
     /// Creates a new [`Divider`].
-    pub fn new<F>(
+    ///
+    /// The on_change function is kept unboxed, no allocation is made
+    /// for it when the view is rebuilt.
+    pub fn new(
         widths: Vec<f32>,
         handle_width: f32,
         handle_height: f32,
@@ -164,24 +398,99 @@ where
         direction: Direction, 
         on_change: F) 
         -> Self
-    where
-        F: 'a + Fn((usize, f32)) -> Message,
     {
         Divider {
+            id: None,
+            content: None,
             widths,
             handle_width,
             handle_height,
-            on_change: Box::new(on_change),
+            on_change,
             on_release: None,
+            on_changes: None,
+            on_release_widths: None,
+            on_release_handle: None,
+            on_divider_event: None,
+            on_drag: None,
+            sender: None,
+            drag_sessions: None,
+            on_hover: None,
+            on_hover_exit: None,
+            on_right_click: None,
+            context_menu: None,
+            on_autofit: None,
+            on_long_press: None,
+            on_auto_scroll: None,
+            long_press_duration: Duration::from_millis(500),
             width: Length::Fill,
             height: Length::Fill,
             handle_offsets,
             include_last_handle: true,
+            min_total: None,
+            max_total: None,
+            shaping: None,
+            steps: vec![],
+            snap_hysteresis: SNAP_HYSTERESIS,
+            step_labels: vec![],
+            step: Some(1.0),
+            min_delta: 0.0,
+            ghost: false,
+            guides: false,
+            crosshair: false,
+            ticks: None,
+            marks: vec![],
+            keyboard_step: 1.0,
+            wheel_step: None,
+            invert_wheel: false,
+            transition: None,
+            fade_out: None,
+            disabled: false,
+            clamp: true,
+            content_minimums: false,
+            track_only: false,
+            grab_full_length: false,
+            mirror: false,
+            clamp_to_viewport: false,
+            resize_strategy: None,
+            on_out_of_range: None,
+            on_limit: None,
+            on_hidden: None,
+            on_snap: None,
+            on_swap: None,
+            on_step: None,
+            collapse_below: None,
+            default_widths: None,
+            weights: None,
+            on_redistribute: None,
+            shrink_priority: vec![],
+            frozen: 0,
+            scroll_offset: 0.0,
+            integer: false,
+            emit_ratios: false,
+            units: None,
+            gaps: vec![],
             direction,
             class: Theme::default(),
+            indexed_style: None,
         }
     }
 
+    /// Sets the [`Id`] of the [`Divider`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the content the [`Divider`] resizes.
+    ///
+    /// The [`Divider`] takes the size of the content and draws the handles
+    /// in an overlay, so they stay above the content and are not clipped
+    /// by the containers around it. No stack is needed.
+    pub fn content(mut self, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
     /// Sets the release message of the [`Divider`].
     /// This is called when the mouse is released from the Divider.
     ///
@@ -192,6 +501,115 @@ where
         self.on_release = Some(on_release);
         self
     }
+
+    /// Sets the message produced when a single input changes several
+    /// containers, like a [`Divider::mirror`] drag or a resize with the
+    /// [`Divider::resize_strategy`], with all the changes in the order
+    /// they are applied, see [`resize::apply_all`]. The update never sees
+    /// the layout halfway through.
+    pub fn on_changes(mut self, on_changes: impl Fn(Vec<(usize, f32)>) -> Message + 'a) -> Self {
+        self.on_changes = Some(Box::new(on_changes));
+        self
+    }
+
+    /// Sets the message produced when a drag is finished, with the final widths or heights.
+    ///
+    /// Saving the layout or recomputing what depends on the sizes can
+    /// then be done once per drag.
+    pub fn on_release_widths(mut self, on_release_widths: impl Fn(Vec<f32>) -> Message + 'a) -> Self {
+        self.on_release_widths = Some(Box::new(on_release_widths));
+        self
+    }
+
+    /// Sets the message produced when a drag is finished, with the index of the
+    /// released handle and the final width or height of its container, so only
+    /// the touched container needs saving.
+    pub fn on_release_handle(mut self, on_release_handle: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_release_handle = Some(Box::new(on_release_handle));
+        self
+    }
+
+    /// Sets the message produced when a drag starts, moves and ends, with
+    /// all the details of the drag in a [`DividerEvent`]. It can replace the
+    /// separate on_change and on_release messages.
+    pub fn on_divider_event(mut self, on_divider_event: impl Fn(DividerEvent) -> Message + 'a) -> Self {
+        self.on_divider_event = Some(Box::new(on_divider_event));
+        self
+    }
+
+    /// Sets a single message for the whole drag, with its [`DragPhase`], the
+    /// index of the handle and the width or height of the container.
+    /// It is published alongside on_change and the release messages.
+    pub fn on_drag(mut self, on_drag: impl Fn(DragPhase, usize, f32) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Sends the changes to the channel, with the [`Id`] of the divider,
+    /// instead of publishing the on_change message. The receiver is a
+    /// stream, to debounce or aggregate the changes in a subscription.
+    pub fn sender(mut self, sender: mpsc::UnboundedSender<DividerChange>) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    /// Sends a [`DragSession`] to the channel each time a drag starts. The
+    /// session is a stream of the [`DividerEvent`]s of the drag, which ends
+    /// after the one ending or canceling it.
+    pub fn drag_sessions(mut self, sender: mpsc::UnboundedSender<DragSession>) -> Self {
+        self.drag_sessions = Some(sender);
+        self
+    }
+
+    /// Sets the message produced when the cursor enters a handle of the [`Divider`].
+    /// The index of the hovered handle is passed so the matching container can be highlighted.
+    pub fn on_hover(mut self, on_hover: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the message produced when the cursor leaves a handle of the [`Divider`].
+    pub fn on_hover_exit(mut self, on_hover_exit: Message) -> Self {
+        self.on_hover_exit = Some(on_hover_exit);
+        self
+    }
+
+    /// Sets the message produced when a handle of the [`Divider`] is right-clicked.
+    /// The index of the handle and the cursor position are passed, e.g. to open a context menu.
+    pub fn on_right_click(mut self, on_right_click: impl Fn(usize, Point) -> Message + 'a) -> Self {
+        self.on_right_click = Some(Box::new(on_right_click));
+        self
+    }
+
+    /// Sets the context menu shown when a handle of the [`Divider`] is right-clicked.
+    /// The entries are built for the index of the clicked handle, each one being
+    /// a label and the message produced when it is selected.
+    pub fn context_menu(mut self, entries: impl Fn(usize) -> Vec<(String, Message)> + 'a) -> Self {
+        self.context_menu = Some(Box::new(entries));
+        self
+    }
+
+    /// Sets the message produced when a handle of the [`Divider`] is double-clicked.
+    /// The index of the handle is passed so the application can measure the content
+    /// and set the optimal width, the usual spreadsheet behavior.
+    pub fn on_autofit(mut self, on_autofit: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_autofit = Some(Box::new(on_autofit));
+        self
+    }
+
+    /// Sets the message produced when a handle of the [`Divider`] is held on a
+    /// touch screen, with the index of the handle and the position of the finger.
+    /// Touch users reach the actions of a right click this way.
+    pub fn on_long_press(mut self, on_long_press: impl Fn(usize, Point) -> Message + 'a) -> Self {
+        self.on_long_press = Some(Box::new(on_long_press));
+        self
+    }
+
+    /// Sets how long a handle has to be held for [`Divider::on_long_press`].
+    pub fn long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press_duration = duration;
+        self
+    }
     /// Sets the width of the [`Divider`] which usually spans the entire width of the items.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -210,6 +628,18 @@ where
         self
     }
 
+    /// Sets where the handles of the [`Divider`] sit on the edges of the
+    /// containers, replacing the handle offsets. The last handle always
+    /// ends at the edge, to stay in bounds.
+    pub fn handle_anchor(mut self, anchor: Anchor) -> Self {
+        let size = match self.direction {
+            Direction::Horizontal => self.handle_width,
+            Direction::Vertical => self.handle_height,
+        };
+        self.handle_offsets = anchor_offsets(self.widths.len(), size, anchor);
+        self
+    }
+
     /// Sets the include_last_handle of the [`Divider`].
     /// If not included, the total width or height will not change
     pub fn include_last_handle(mut self, include: bool) -> Self {
@@ -217,183 +647,1473 @@ where
         self
     }
 
-    /// Sets the direction of the [`Divided`].
-    pub fn direction(mut self, direction: Direction) -> Self {
-        self.direction = direction;
+    /// Sets the minimum total width or height the last handle of the [`Divider`] can shrink to.
+    pub fn min_total(mut self, min_total: f32) -> Self {
+        self.min_total = Some(min_total);
         self
     }
 
-    /// Sets the style of the [`Divider`].
-    #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
-    where
-        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
-    {
-        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+    /// Sets the maximum total width or height the last handle of the [`Divider`] can grow to.
+    pub fn max_total(mut self, max_total: f32) -> Self {
+        self.max_total = Some(max_total);
         self
     }
 
-    /// Sets the style class of the [`Divider`].
-    #[must_use]
-    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
-        self.class = class.into();
+    /// Sets a shaping function of the [`Divider`] for non-linear resizing.
+    ///
+    /// The function receives the position of the handle as a percentage (0.0 to 1.0)
+    /// of the two containers it separates and returns the percentage given to
+    /// the first one. The last handle is not shaped.
+    pub fn shaping(mut self, shaping: impl Fn(f32) -> f32 + 'a) -> Self {
+        self.shaping = Some(Box::new(shaping));
         self
     }
-}
 
-impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for Divider<'_, Message, Theme>
-where
-    Message: Clone,
-    Theme: Catalog,
-    Renderer: iced::advanced::Renderer,
-{
-    fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State>()
+    /// Sets the allowed widths or heights of the [`Divider`] containers.
+    /// While dragging, the container jumps to the nearest allowed value
+    /// that still fits next to the following container.
+    pub fn steps(mut self, steps: Vec<f32>) -> Self {
+        self.steps = steps;
+        self
     }
 
-    fn state(&self) -> tree::State {
-        tree::State::new(State::default())
+    /// Sets how far past the point where the value snaps to another step,
+    /// or opens a collapsed container, the drag has to go before it does,
+    /// so the value doesn't flicker on tiny movements there. Zero turns
+    /// the hysteresis off, it is 4.0 by default.
+    pub fn snap_hysteresis(mut self, snap_hysteresis: f32) -> Self {
+        self.snap_hysteresis = snap_hysteresis;
+        self
     }
 
-    fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: Length::Shrink,
-        }
+    /// Sets the labels of some of the [`Divider::steps`], shown in an overlay
+    /// next to the dragged handle while the cursor is near the step.
+    /// A label of a value that isn't one of the steps is not shown.
+    pub fn step_labels(mut self, step_labels: Vec<(f32, String)>) -> Self {
+        self.step_labels = step_labels;
+        self
     }
 
-    fn layout(
-        &self,
-        _tree: &mut Tree,
-        _renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+    /// Sets the distance a focused handle of the [`Divider`] moves with each arrow key press.
+    /// A handle is focused by pressing it. The default is 1.0.
+    pub fn keyboard_step(mut self, keyboard_step: f32) -> Self {
+        self.keyboard_step = keyboard_step;
+        self
     }
 
-    fn on_event(
-        &mut self,
-        tree: &mut Tree,
-        event: Event,
-        layout: Layout<'_>,
-        cursor: mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-        shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
-    ) -> event::Status {
-        let state = tree.state.downcast_mut::<State>();
-        let is_dragging = state.is_dragging;
-        let total_bounds = layout.bounds();
-        
-        // stores the state
-        let mut widths = vec![];
-        for width in self.widths.iter() {
-            match self.direction {
-                Direction::Horizontal => {
-                    widths.push(*width);
-                },
-                Direction::Vertical => {
-                    widths.push(*width);
-                },
-            }
-        }
-        state.handle_bounds = 
-            get_handle_bounds(
-                total_bounds,
-                &widths,
-                self.handle_width, 
-                self.handle_height,
-                &self.handle_offsets,
-                self.include_last_handle,
-                self.direction);
+    /// Sets the distance a handle of the [`Divider`] moves for each mouse wheel tick
+    /// while the cursor is over it. Wheel nudging is off until a step is set.
+    pub fn wheel_step(mut self, wheel_step: f32) -> Self {
+        self.wheel_step = Some(wheel_step);
+        self
+    }
 
-        state.width_height_bounds =
-            get_width_height_bounds(
-                total_bounds,
-                &widths,
-                self.handle_width, 
-                self.handle_height, 
-                self.direction);
+    /// Inverts the wheel direction of the [`Divider`].
+    /// By default scrolling down moves the handle right or down.
+    pub fn invert_wheel(mut self, invert: bool) -> Self {
+        self.invert_wheel = invert;
+        self
+    }
 
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let index = 
-                    find_mouse_over_handle_bounds(
-                        &state.handle_bounds, cursor);
-                
-                if index.is_some() {
-                    state.is_dragging = true;
-                    state.index = index.unwrap();
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if is_dragging {
-                    if let Some(on_release) = self.on_release.clone() {
-                        shell.publish(on_release);
-                    }
-                    state.is_dragging = false;
-                    state.handle_bounds = vec![];
-                    state.width_height_bounds = vec![];
-                    state.index = 0;
+    /// Sets the duration of the style transition of the [`Divider`] when the
+    /// status changes between active, hovered and dragged.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
 
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { id: _, position }) => {
-                if is_dragging {
-                    let end_x = total_bounds.x+total_bounds.width;
-                    let end_y = total_bounds.y+total_bounds.height;
-                    let handle_bounds = state.handle_bounds[state.index];
-                    let w_h_bounds = state.width_height_bounds[state.index];
-                    let handle_count = state.handle_bounds.len();
-                    let w_h_count = state.width_height_bounds.len();
+    /// Fades the handles of the [`Divider`] to the given opacity after
+    /// some time without interaction. Hovering or dragging a handle
+    /// restores the full opacity.
+    pub fn fade_out(mut self, after: Duration, opacity: f32) -> Self {
+        self.fade_out = Some((after, opacity));
+        self
+    }
 
-                    match self.direction {
-                        Direction::Horizontal => {
-                            if (position.x - handle_bounds.x + handle_bounds.width/2.0).abs() > 0.99 {
-                                let new_value = 
-                                    // Moving left
-                                    if position.x < w_h_bounds.x && state.index == 0 {
+    /// Disables the [`Divider`], the handles are drawn with the
+    /// disabled status and don't react to any input.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
 
-                                        state.handle_bounds[state.index].x = w_h_bounds.x;
-                                        (state.index, 0.0)
-                                    } else 
-                                    // Moving left stopping at next divider
-                                    if state.index > 0 && position.x < state.handle_bounds[state.index-1].x {
+    /// Sets whether negative or non-finite widths are treated as zero when
+    /// placing the handles of the [`Divider`]. The default is true.
+    ///
+    /// Either way, such widths are reported once with a warning in debug builds
+    /// and through [`Divider::on_out_of_range`].
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
 
-                                        state.handle_bounds[state.index].x = state.handle_bounds[state.index-1].x;
-                                        (state.index, 0.0)
-                                    } else
-                                    // Moving right: stop at next divider
-                                    if  state.index < handle_count-1 && (state.index < handle_count) && 
-                                        (position.x > state.handle_bounds[state.index+1].x) {
+    /// Keeps the containers at least at the natural size of their content
+    /// while dragging, measured from the [`measured`](crate::measure::measured)
+    /// panes of the content, in the order of the containers. A container
+    /// already smaller than its content is not crushed further.
+    pub fn content_minimums(mut self, content_minimums: bool) -> Self {
+        self.content_minimums = content_minimums;
+        self
+    }
 
-                                        state.handle_bounds[state.index].x = state.handle_bounds[state.index+1].x;
-                                        (state.index, 0.0)
+    /// Draws a thin line across the whole [`Divider`] instead of the handles,
+    /// for a minimal look. The whole line can be dragged and takes the
+    /// style of the hovered or dragged handle.
+    pub fn track_only(mut self, track_only: bool) -> Self {
+        self.track_only = track_only;
+        self
+    }
+
+    /// Lets the handles be grabbed anywhere along the boundary between the
+    /// containers, not only on the handle, like the edge of a tall sidebar.
+    /// The handles are drawn as usual.
+    pub fn grab_full_length(mut self, grab_full_length: bool) -> Self {
+        self.grab_full_length = grab_full_length;
+        self
+    }
+
+    /// Moves the paired handle symmetrically around the center when a
+    /// handle is dragged, the last but one with the first and so on, and
+    /// produces the change of both in one message of [`Divider::on_changes`],
+    /// the dragged one first. Side panels around a centered pane stay equal
+    /// this way. Without on_changes the drag isn't mirrored.
+    pub fn mirror(mut self, mirror: bool) -> Self {
+        self.mirror = mirror;
+        self
+    }
+
+    /// Sets the message produced with the offset to scroll the enclosing
+    /// scrollable by, each frame a handle is dragged near the edge of its
+    /// visible part, so the drag can go on beyond it. Scroll it with
+    /// `scrollable::scroll_by`, the handle follows the scrolled content.
+    pub fn on_auto_scroll(mut self, on_auto_scroll: impl Fn(AbsoluteOffset) -> Message + 'a) -> Self {
+        self.on_auto_scroll = Some(Box::new(on_auto_scroll));
+        self
+    }
+
+    /// Applies the strategy to the widths or heights when the size of the
+    /// [`Divider`] along its direction changes, with the window for example,
+    /// producing the change of every container in one message of
+    /// [`Divider::on_changes`], which it requires. The [`Divider`] has to
+    /// fill its parent, see [`resize::resize_with`].
+    pub fn resize_strategy(mut self, strategy: resize::Strategy) -> Self {
+        self.resize_strategy = Some(strategy);
+        self
+    }
+
+    /// Keeps the dragged handle inside the visible part of the [`Divider`],
+    /// so a divider larger than the window can't be dragged out of reach.
+    pub fn clamp_to_viewport(mut self, clamp_to_viewport: bool) -> Self {
+        self.clamp_to_viewport = clamp_to_viewport;
+        self
+    }
+
+    /// Sets the message produced when a width given to the [`Divider`] is
+    /// negative or not finite, usually a bookkeeping error in the application.
+    pub fn on_out_of_range(mut self, on_out_of_range: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_out_of_range = Some(Box::new(on_out_of_range));
+        self
+    }
+
+    /// Sets the message produced the first time a drag pins a handle of
+    /// the [`Divider`] at a [`Limit`], e.g. to show that the minimum width is reached.
+    pub fn on_limit(mut self, on_limit: impl Fn(usize, Limit) -> Message + 'a) -> Self {
+        self.on_limit = Some(Box::new(on_limit));
+        self
+    }
+
+    /// Sets the message produced when a drag collapses a container of the
+    /// [`Divider`] to zero. Its previous size is kept and brought back with
+    /// [`operation::restore`](crate::operation::restore).
+    pub fn on_hidden(mut self, on_hidden: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_hidden = Some(Box::new(on_hidden));
+        self
+    }
+
+    /// Sets the message produced when a drag locks the handle onto one of
+    /// the steps, e.g. to play a tick sound.
+    pub fn on_snap(mut self, on_snap: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_snap = Some(Box::new(on_snap));
+        self
+    }
+
+    /// Lets a handle be dragged past its neighbor, which ends the drag and
+    /// produces the message with the indices of the two containers to swap.
+    ///
+    /// The container squeezed between the two handles swaps with the one
+    /// beyond the crossed handle, so dragging the handles around a container
+    /// moves it along the others. Without it, handles stop at their neighbors.
+    pub fn on_swap(mut self, on_swap: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_swap = Some(Box::new(on_swap));
+        self
+    }
+
+    /// Sets the message produced each time a drag moves the value
+    /// to another step, see [`Divider::step`]. Without a step it is
+    /// produced for every change.
+    pub fn on_step(mut self, on_step: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_step = Some(Box::new(on_step));
+        self
+    }
+
+    /// Sets the step the dragged values are rounded to, 1.0 by default.
+    /// With None the values are continuous, for smooth fine-grained splits.
+    pub fn step(mut self, step: impl Into<Option<f32>>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets how far a drag has to move the value from the last published
+    /// one before it is published again, for changes causing expensive
+    /// work. Values pinned at a limit are always published.
+    pub fn min_delta(mut self, min_delta: f32) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
+
+    /// Only moves the handle while it is dragged and publishes the change
+    /// when it is released, so the view isn't rebuilt on every move.
+    pub fn ghost(mut self, ghost: bool) -> Self {
+        self.ghost = ghost;
+        self
+    }
+
+    /// Draws a guide line across the whole [`Divider`] at the dragged handle,
+    /// to line it up with the edges of the content.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Draws a guide line across the whole [`Divider`] at the cursor while
+    /// dragging, which the handle lags behind when it snaps or steps.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Draws tick marks along the track of the hovered or dragged handle,
+    /// at the values it can be snapped to, see [`TickMarks`].
+    pub fn ticks(mut self, ticks: impl Into<Option<TickMarks>>) -> Self {
+        self.ticks = ticks.into();
+        self
+    }
+
+    /// Sets labels shown at the given values along the track of the
+    /// hovered or dragged handle, like "min" or "default".
+    pub fn marks(mut self, marks: Vec<(f32, String)>) -> Self {
+        self.marks = marks;
+        self
+    }
+
+    /// Sets the widths or heights the [`Divider`] is reset to by
+    /// [`operation::reset_all`](crate::operation::reset_all). A double tap
+    /// on a handle on a touch screen restores the default of its container.
+    pub fn default_widths(mut self, default_widths: Vec<f32>) -> Self {
+        self.default_widths = Some(default_widths);
+        self
+    }
+
+    /// Sets the weights of the containers, like 1:2:1, restored scaled to
+    /// the current total by [`operation::reset_to_weights`](crate::operation::reset_to_weights).
+    pub fn weights(mut self, weights: Vec<f32>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Sets the message produced with all the widths or heights when the
+    /// number of containers changes, redistributed to keep the previous total.
+    pub fn on_redistribute(
+        mut self,
+        redistribution: resize::Redistribution,
+        on_redistribute: impl Fn(Vec<f32>) -> Message + 'a,
+    ) -> Self {
+        self.on_redistribute = Some((redistribution, Box::new(on_redistribute)));
+        self
+    }
+
+    /// Sets the priority of each container when the containers added
+    /// need space, see [`Divider::on_redistribute`]. The containers with
+    /// the lowest priority shrink first, so an editor with the highest
+    /// one shrinks last, and the one with the highest priority takes the
+    /// space of removed containers. It replaces the redistribution.
+    ///
+    /// Apply [`resize_by_priority`](crate::resize::resize_by_priority)
+    /// with the same priorities when the window is resized.
+    pub fn shrink_priority(mut self, shrink_priority: Vec<u8>) -> Self {
+        self.shrink_priority = shrink_priority;
+        self
+    }
+
+    /// Sets the size below which a dragged container of the [`Divider`]
+    /// snaps to zero, so containers are either usable or hidden.
+    pub fn collapse_below(mut self, collapse_below: f32) -> Self {
+        self.collapse_below = Some(collapse_below);
+        self
+    }
+
+    /// Freezes the first containers of the [`Divider`], like frozen columns
+    /// of a spreadsheet. They stay in place when the others are scrolled
+    /// with [`Divider::scroll_offset`], their handles still resize them.
+    pub fn frozen(mut self, frozen: usize) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// Sets how far the containers after the frozen ones are scrolled.
+    /// Handles scrolled under the frozen containers are hidden. The values
+    /// published are the sizes of the containers, they don't include the offset.
+    pub fn scroll_offset(mut self, scroll_offset: f32) -> Self {
+        self.scroll_offset = scroll_offset;
+        self
+    }
+
+    /// Rounds every value published by the [`Divider`] to a whole number,
+    /// for applications storing the sizes as integers.
+    pub fn integer(mut self, integer: bool) -> Self {
+        self.integer = integer;
+        self
+    }
+
+    /// Publishes the size of the container as a fraction of the total of
+    /// the widths or heights before the change, instead of pixels.
+    ///
+    /// The gaps are not part of the total, the fractions share the space of
+    /// the containers, so [`from_weights`](crate::resize::from_weights) with
+    /// the space left by the gaps gives the sizes back.
+    pub fn emit_ratios(mut self, emit_ratios: bool) -> Self {
+        self.emit_ratios = emit_ratios;
+        self
+    }
+
+    /// Sets the units of the widths or heights, e.g. seconds or rows.
+    ///
+    /// to_value maps a position in pixels along the containers, the gaps
+    /// left out, to a position in units and from_value maps it back. The
+    /// widths, default widths, steps, step, step labels, marks, ticks,
+    /// totals, collapse size, keyboard and wheel steps and min delta are
+    /// given in units, and the published values are in units too, or
+    /// ratios of the total in units with emit_ratios. The gaps, scroll
+    /// offset and snap hysteresis stay in pixels.
+    pub fn units(
+        mut self,
+        to_value: impl Fn(f32) -> f32 + 'a,
+        from_value: impl Fn(f32) -> f32 + 'a,
+    ) -> Self {
+        self.units = Some((Box::new(to_value), Box::new(from_value)));
+        self
+    }
+
+    /// Sets the gaps between the containers of the [`Divider`], for rows or columns
+    /// with spacing. The gap at index i is the space after container i and its
+    /// handle is centered in it. The published values stay the container sizes.
+    pub fn gaps(mut self, gaps: Vec<f32>) -> Self {
+        self.gaps = gaps;
+        self
+    }
+
+    /// Sets the direction of the [`Divided`].
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the direction like [`Divider::direction`] and turns the handles
+    /// with it. Changing the direction swaps the handle width and height, so
+    /// the handles stay thin along the drag and long across it, and centers
+    /// the handles again for their new thickness.
+    pub fn orient(mut self, direction: Direction) -> Self {
+        if direction != self.direction {
+            std::mem::swap(&mut self.handle_width, &mut self.handle_height);
+            let thickness = match direction {
+                Direction::Horizontal => self.handle_width,
+                Direction::Vertical => self.handle_height,
+            };
+            self.handle_offsets = default_offsets(self.widths.len(), thickness);
+        }
+        self.direction = direction;
+        self
+    }
+
+    fn gap(&self, index: usize) -> f32 {
+        self.gaps.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// The extent of the containers and the gaps before the container at index.
+    fn extent_before(&self, index: usize) -> f32 {
+        self.widths[..index].iter().sum::<f32>() + self.gaps.iter().take(index).sum::<f32>()
+    }
+
+    /// Applies the shaping function over the span of the two containers of the handle.
+    fn shape(&self, (index, value): (usize, f32)) -> (usize, f32) {
+        let Some(shaping) = &self.shaping else {
+            return (index, value);
+        };
+        if index >= self.widths.len()-1 {
+            return (index, value);
+        }
+        let span = self.widths[index] + self.widths[index+1];
+        if span <= 0.0 {
+            return (index, value);
+        }
+        let percent = (value / span).clamp(0.0, 1.0);
+
+        (index, self.quantize(shaping(percent).clamp(0.0, 1.0) * span))
+    }
+
+    /// Moves the handle at index by step, returning None if it can't move.
+    fn nudge(&self, index: usize, step: f32) -> Option<(usize, f32)> {
+        if index >= self.widths.len() {
+            return None;
+        }
+        let is_last = index == self.widths.len()-1;
+        if is_last && !self.include_last_handle {
+            return None;
+        }
+        let mut value = (self.widths[index] + step).max(0.0);
+        if !is_last {
+            value = value.min(self.widths[index] + self.widths[index+1]);
+        }
+
+        let new_value = keep_order(&self.widths, self.round(self.clamp_total((index, value))));
+        if new_value.1 == self.widths[index] {
+            None
+        } else {
+            Some(new_value)
+        }
+    }
+
+    /// Moves the value to the nearest of the allowed steps.
+    fn snap_to_steps(&self, (index, value): (usize, f32)) -> (usize, f32) {
+        let span = if index < self.widths.len()-1 {
+            self.widths[index] + self.widths[index+1]
+        } else {
+            f32::INFINITY
+        };
+
+        let nearest = self.steps.iter()
+            .filter(|step| **step >= 0.0 && **step <= span)
+            .min_by(|a, b| (*a - value).abs().total_cmp(&(*b - value).abs()));
+
+        match nearest {
+            Some(step) => (index, *step),
+            None => (index, value),
+        }
+    }
+
+    /// Snaps the value to the steps and collapses it, keeping the value
+    /// published last while it can be reached within the hysteresis.
+    fn snap(&self, state: &State, (index, value): (usize, f32)) -> (usize, f32) {
+        let snap = |value: f32| self.collapse(self.snap_to_steps((index, value))).1;
+
+        match state.published {
+            Some(previous) if !self.steps.is_empty() || self.collapse_below.is_some() =>
+                (index, keep_snapped(value, previous, self.snap_hysteresis, snap)),
+            _ => (index, snap(value)),
+        }
+    }
+
+    fn round(&self, (index, value): (usize, f32)) -> (usize, f32) {
+        if self.integer {
+            (index, value.round())
+        } else {
+            (index, value)
+        }
+    }
+
+    /// Converts the values given in units to pixels, once all the options are set.
+    fn into_pixels(mut self) -> Self {
+        let Some((to_value, from_value)) = &self.units else {
+            return self;
+        };
+        let origin = to_value(0.0);
+        let size = |value: f32| from_value(origin + value) - from_value(origin);
+
+        self.widths = sizes_to_pixels(&self.widths, origin, from_value);
+        self.default_widths = self.default_widths.as_ref()
+            .map(|default_widths| sizes_to_pixels(default_widths, origin, from_value));
+        self.steps = self.steps.iter().map(|step| size(*step)).collect();
+        self.step_labels = self.step_labels.iter()
+            .map(|(step, label)| (size(*step), label.clone()))
+            .collect();
+        self.marks = self.marks.iter()
+            .map(|(mark, label)| (size(*mark), label.clone()))
+            .collect();
+        if let Some(TickMarks::Every(distance)) = &self.ticks {
+            self.ticks = Some(TickMarks::Every(size(*distance)));
+        }
+        self.step = self.step.map(size);
+        self.min_total = self.min_total.map(size);
+        self.max_total = self.max_total.map(size);
+        self.collapse_below = self.collapse_below.map(size);
+        self.keyboard_step = size(self.keyboard_step);
+        self.wheel_step = self.wheel_step.map(size);
+        self.min_delta = size(self.min_delta);
+        self
+    }
+
+    /// Converts the size in pixels of a container starting at start to units.
+    fn to_units(&self, start: f32, size: f32) -> f32 {
+        match &self.units {
+            Some((to_value, _)) if self.integer =>
+                (to_value(start + size) - to_value(start)).round(),
+            Some((to_value, _)) => to_value(start + size) - to_value(start),
+            None => size,
+        }
+    }
+
+    /// Converts the sizes in pixels of all the containers to units.
+    fn to_unit_widths(&self, widths: &[f32]) -> Vec<f32> {
+        let mut start = 0.0;
+        widths.iter()
+            .map(|width| {
+                let value = self.to_units(start, *width);
+                start += width;
+                value
+            })
+            .collect()
+    }
+
+    /// Converts a value in pixels to the ratio or units published.
+    fn to_published(&self, (index, value): (usize, f32)) -> (usize, f32) {
+        let start: f32 = self.widths.iter().take(index).sum();
+        let value = self.to_units(start, value);
+        let total = self.to_units(0.0, self.widths.iter().sum());
+        if self.emit_ratios && total > 0.0 {
+            (index, value / total)
+        } else {
+            (index, value)
+        }
+    }
+
+    /// Converts the sizes in pixels of all the containers to the ratios or units published.
+    fn to_published_widths(&self, widths: &[f32]) -> Vec<f32> {
+        let total = self.to_units(0.0, self.widths.iter().sum());
+        self.to_unit_widths(widths).into_iter()
+            .map(|value| if self.emit_ratios && total > 0.0 { value / total } else { value })
+            .collect()
+    }
+
+    fn collapse(&self, change: (usize, f32)) -> (usize, f32) {
+        match self.collapse_below {
+            Some(threshold) => snap_collapsed(&self.widths, threshold, change),
+            None => change,
+        }
+    }
+
+    /// Keeps the total within min_total and max_total when the last handle is
+    /// moved, the total including the gaps.
+    fn clamp_total(&self, (index, value): (usize, f32)) -> (usize, f32) {
+        if !self.include_last_handle || index != self.widths.len()-1 {
+            return (index, value);
+        }
+        let others = self.extent_before(index);
+        let mut total = others + value;
+
+        if let Some(max_total) = self.max_total {
+            total = total.min(max_total);
+        }
+        if let Some(min_total) = self.min_total {
+            total = total.max(min_total);
+        }
+        (index, (total - others).max(0.0))
+    }
+
+    fn update_bounds(&self, state: &mut State, total_bounds: Rectangle) {
+        let widths = state.resized.as_ref().unwrap_or(&self.widths);
+        let mut widths: Vec<f32> = if self.clamp {
+            widths.iter()
+                .map(|width| if width.is_finite() { width.max(0.0) } else { 0.0 })
+                .collect()
+        } else {
+            widths.clone()
+        };
+        // the handles of a ghost drag are drawn where they were dragged to
+        if let Some(change) = state.ghost.filter(|(index, _)| *index < widths.len()) {
+            self.apply_dragged(&mut widths, change);
+        }
+
+        state.handle_bounds = 
+            get_handle_bounds(
+                total_bounds,
+                &widths,
+                &self.gaps,
+                self.handle_width, 
+                self.handle_height,
+                &self.handle_offsets,
+                self.include_last_handle,
+                self.direction);
+
+        state.width_height_bounds =
+            get_width_height_bounds(
+                total_bounds,
+                &widths,
+                &self.gaps,
+                self.handle_width, 
+                self.handle_height, 
+                self.direction);
+
+        if self.scroll_offset != 0.0 {
+            scroll_bounds(
+                &mut state.handle_bounds,
+                &mut state.width_height_bounds,
+                self.frozen,
+                self.scroll_offset,
+                self.direction);
+        }
+
+        state.hit_bounds = bias_hit_bounds(&state.handle_bounds, &widths, self.direction);
+
+        if self.track_only || self.grab_full_length {
+            let area = state.width_height_bounds.iter()
+                .copied()
+                .reduce(|area, bounds| area.union(&bounds));
+            if let Some(area) = area {
+                for bounds in state.hit_bounds.iter_mut() {
+                    *bounds = full_length(*bounds, area, self.direction);
+                }
+            }
+        }
+    }
+
+    /// Reports a negative or non-finite width when it first shows up,
+    /// the warning of debug builds is only printed once.
+    fn check_range(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let out_of_range = self.widths.iter()
+            .position(|width| !width.is_finite() || *width < 0.0);
+
+        if let (Some(index), false) = (out_of_range, state.is_out_of_range) {
+            #[cfg(debug_assertions)]
+            if !state.has_warned {
+                state.has_warned = true;
+                eprintln!(
+                    "iced_divider: width {} at index {index} is out of range, \
+                    widths must be finite and not negative",
+                    self.widths[index]);
+            }
+
+            if let Some(on_out_of_range) = &self.on_out_of_range {
+                shell.publish(on_out_of_range(index, self.widths[index]));
+            }
+        }
+        state.is_out_of_range = out_of_range.is_some();
+    }
+
+    /// Publishes the limit the first time a drag pins the value at it.
+    /// Besides the ends of the containers, the value is pinned when the
+    /// clamping moved it from where it was dragged, like min_total or the
+    /// minimums of the content do.
+    fn publish_limit(
+        &self,
+        state: &mut State,
+        unclamped: f32,
+        (index, value): (usize, f32),
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let limit = if value <= 0.0 || value > unclamped {
+            Some(Limit::Start)
+        } else if value < unclamped {
+            Some(Limit::End)
+        } else if index < self.widths.len()-1 {
+            (value >= self.widths[index] + self.widths[index+1]).then_some(Limit::End)
+        } else {
+            let total = self.extent_before(index) + value;
+            self.max_total
+                .filter(|max_total| total >= *max_total)
+                .map(|_| Limit::End)
+        };
+
+        if limit.is_some() && limit != state.limit {
+            if let (Some(limit), Some(on_limit)) = (limit, &self.on_limit) {
+                shell.publish(on_limit(index, limit));
+            }
+        }
+        state.limit = limit;
+    }
+
+    /// Rounds the value to the nearest multiple of the step.
+    fn quantize(&self, value: f32) -> f32 {
+        match self.step {
+            Some(step) if step > 0.0 => (value / step).round() * step,
+            _ => value,
+        }
+    }
+
+    fn exceeds_min_delta(&self, state: &State, (_, value): (usize, f32)) -> bool {
+        match state.published {
+            Some(published) if state.limit.is_none() => 
+                (value - published).abs() >= self.min_delta,
+            _ => true,
+        }
+    }
+
+    /// Publishes the change of a drag, together with the one of the paired
+    /// handle when mirrored.
+    fn publish_dragged(&self, change: (usize, f32), shell: &mut Shell<'_, Message>) {
+        match self.mirrored_change(change) {
+            Some(mirrored) => self.publish_changes(vec![change, mirrored], shell),
+            None => self.publish_change(change, shell),
+        }
+    }
+
+    /// Applies the change of a drag to the widths, and the one of the
+    /// paired handle when mirrored.
+    fn apply_dragged(&self, widths: &mut [f32], change: (usize, f32)) {
+        let mirrored = self.mirrored_change(change);
+        resize::apply(widths, change);
+        if let Some(mirrored) = mirrored {
+            resize::apply(widths, mirrored);
+        }
+    }
+
+    /// The change of the handle paired with the changed one, when mirrored.
+    fn mirrored_change(&self, change: (usize, f32)) -> Option<(usize, f32)> {
+        if !self.mirror || self.on_changes.is_none() {
+            return None;
+        }
+        mirrored(&self.widths, change)
+    }
+
+    /// Publishes the changes of a single input in one message of on_changes.
+    fn publish_changes(&self, changes: Vec<(usize, f32)>, shell: &mut Shell<'_, Message>) {
+        if let Some(on_changes) = &self.on_changes {
+            let changes = changes.into_iter()
+                .map(|change| self.to_published(change))
+                .collect();
+            shell.publish(on_changes(changes));
+        }
+    }
+
+    fn publish_change(&self, change: (usize, f32), shell: &mut Shell<'_, Message>) {
+        let (index, value) = self.to_published(change);
+        match &self.sender {
+            Some(sender) => {
+                // a closed receiver drops the change
+                let _ = sender.unbounded_send(DividerChange {
+                    id: self.id.clone(),
+                    index,
+                    value,
+                });
+            },
+            None => shell.publish((self.on_change)((index, value))),
+        }
+    }
+
+    fn publish_drag(
+        &self,
+        state: &mut State,
+        phase: DragPhase,
+        change: (usize, f32),
+        previous: f32,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (index, value) = self.to_published(change);
+        let event = DividerEvent {
+            index,
+            value,
+            previous: self.to_published((index, previous)).1,
+            phase,
+            modifiers: state.modifiers,
+        };
+        if let Some(on_divider_event) = &self.on_divider_event {
+            shell.publish(on_divider_event(event));
+        }
+        if let Some(on_drag) = &self.on_drag {
+            shell.publish(on_drag(phase, index, value));
+        }
+
+        if let (Some(drag_sessions), DragPhase::Started) = (&self.drag_sessions, phase) {
+            let (sender, events) = mpsc::unbounded();
+            // a closed receiver drops the session
+            let session = DragSession { id: self.id.clone(), index, events };
+            if drag_sessions.unbounded_send(session).is_ok() {
+                state.session = Some(sender);
+            }
+        }
+        if let Some(session) = &state.session {
+            let _ = session.unbounded_send(event);
+        }
+        if matches!(phase, DragPhase::Ended | DragPhase::Canceled) {
+            // dropping the sender ends the stream of the session
+            state.session = None;
+        }
+    }
+
+    /// The containers to swap when the dragged handle crossed its neighbor,
+    /// see [`Divider::on_swap`].
+    fn crossing(&self, state: &State, position: Point) -> Option<(usize, usize)> {
+        self.on_swap.as_ref()?;
+        let axis = |point: Point| match self.direction {
+            Direction::Horizontal => point.x,
+            Direction::Vertical => point.y,
+        };
+        let index = state.index;
+
+        let previous = index.checked_sub(1).and_then(|i| state.handle_bounds.get(i));
+        if previous.is_some_and(|bounds| axis(position) < axis(bounds.position())) {
+            return Some((index-1, index));
+        }
+        let next = state.handle_bounds.get(index+1);
+        if next.is_some_and(|bounds| axis(position) > axis(bounds.position()))
+            && index+2 < self.widths.len() {
+            return Some((index+1, index+2));
+        }
+        None
+    }
+
+    /// Moves the position inside the viewport, far enough from its edges
+    /// for the whole handle to stay visible.
+    fn clamp_to(&self, position: Point, viewport: Rectangle) -> Point {
+        match self.direction {
+            Direction::Horizontal => {
+                let margin = (self.handle_width/2.0).min(viewport.width/2.0);
+                Point {
+                    x: position.x.clamp(viewport.x + margin, viewport.x + viewport.width - margin),
+                    ..position
+                }
+            },
+            Direction::Vertical => {
+                let margin = (self.handle_height/2.0).min(viewport.height/2.0);
+                Point {
+                    y: position.y.clamp(viewport.y + margin, viewport.y + viewport.height - margin),
+                    ..position
+                }
+            },
+        }
+    }
+
+    /// Ends the drag, back at its start, and publishes the swap.
+    fn swap(&self, state: &mut State, (a, b): (usize, usize), shell: &mut Shell<'_, Message>) {
+        self.cancel_drag(state, shell);
+        if let Some(on_swap) = &self.on_swap {
+            shell.publish(on_swap(a, b));
+        }
+        state.end_drag();
+    }
+
+    /// Redistributes the widths when the number of containers changed since
+    /// the last event, ending a drag of a handle that may be gone.
+    fn update_count(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let count = self.widths.len();
+        let total = self.widths.iter().sum();
+        let previous_count = std::mem::replace(&mut state.count, count);
+        let previous_total = std::mem::replace(&mut state.total, total);
+
+        if previous_count == count {
+            return;
+        }
+        if state.is_dragging {
+            self.cancel_drag(state, shell);
+            state.end_drag();
+        }
+        if state.focused.is_some_and(|focused| focused >= count) {
+            state.focused = None;
+        }
+
+        if let (Some((redistribution, on_redistribute)), true) = 
+            (&self.on_redistribute, previous_count > 0) {
+            let widths = if self.shrink_priority.is_empty() {
+                resize::redistribute(&self.widths, previous_total, *redistribution)
+            } else {
+                let mut widths = self.widths.clone();
+                resize::resize_by_priority(&mut widths, total, previous_total, &self.shrink_priority);
+                widths
+            };
+            if widths != self.widths {
+                shell.publish(on_redistribute(self.to_published_widths(&widths)));
+            }
+        }
+    }
+
+    /// Applies the resize strategy when the layout changed the size of the
+    /// divider. The resized widths are drawn until they are published.
+    fn update_extent(&self, state: &mut State, size: Size) {
+        let (Some(strategy), Some(_)) = (self.resize_strategy, &self.on_changes) else {
+            return;
+        };
+        let extent = match self.direction {
+            Direction::Horizontal => size.width,
+            Direction::Vertical => size.height,
+        };
+        let previous = state.extent.replace(extent);
+
+        if let Some(previous) = previous.filter(|previous| *previous > 0.0 && *previous != extent) {
+            // a second resize before the first is published starts from it
+            let mut widths = state.resized.take().unwrap_or_else(|| self.widths.clone());
+            resize::resize_with(&mut widths, previous, extent, strategy);
+            state.resized = Some(widths);
+        }
+    }
+
+    /// Publishes the widths resized by the strategy, all in one message.
+    /// Each change is applied after the previous one, so the last container
+    /// gets its width once the others are set.
+    fn publish_resized(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if let Some(widths) = state.resized.take() {
+            self.publish_changes(widths.into_iter().enumerate().collect(), shell);
+        }
+    }
+
+    /// Publishes the cancelation of the drag, back at its start value.
+    fn cancel_drag(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if let Some(start) = state.drag_start.get(state.index).copied() {
+            self.publish_drag(state, DragPhase::Canceled, (state.index, start), start, shell);
+        }
+    }
+
+    /// Publishes the value once each time the drag moves it to another step.
+    fn publish_step(
+        &self,
+        state: &mut State,
+        (index, value): (usize, f32),
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if state.stepped != Some(value) {
+            if let Some(on_step) = &self.on_step {
+                shell.publish(on_step(index, self.to_published((index, value)).1));
+            }
+        }
+        state.stepped = Some(value);
+    }
+
+    /// Publishes the step the first time a drag locks onto it.
+    fn publish_snap(
+        &self,
+        state: &mut State,
+        (index, value): (usize, f32),
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let snapped = self.steps.contains(&value).then_some(value);
+
+        if snapped.is_some() && snapped != state.snapped {
+            if let Some(on_snap) = &self.on_snap {
+                shell.publish(on_snap(index, self.to_published((index, value)).1));
+            }
+        }
+        state.snapped = snapped;
+    }
+
+    /// Remembers the size a container had when the drag started once
+    /// the drag collapses it, and forgets it when it is opened again.
+    fn track_hidden(
+        &self,
+        state: &mut State,
+        (index, value): (usize, f32),
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let mut panes = vec![(index, value <= 0.0)];
+        if let (Some(width), Some(next)) = 
+            (state.drag_start.get(index), state.drag_start.get(index+1)) {
+            panes.push((index+1, value >= width + next));
+        }
+
+        for (pane, is_hidden) in panes {
+            let Some(&previous) = state.drag_start.get(pane) else {
+                continue;
+            };
+
+            if !is_hidden {
+                state.hidden.remove(&pane);
+            } else if previous > 0.0 && !state.hidden.contains_key(&pane) {
+                state.hidden.insert(pane, self.to_published((pane, previous)).1);
+                if let Some(on_hidden) = &self.on_hidden {
+                    shell.publish(on_hidden(pane));
+                }
+            }
+        }
+    }
+
+    fn status(&self, state: &State, cursor: mouse::Cursor) -> Status {
+        let is_mouse_over = 
+            find_mouse_over_handle_bounds(
+                &state.hit_bounds,
+                cursor,);
+
+        if self.disabled {
+            Status::Disabled
+        } else if state.is_dragging {
+            Status::Dragged
+        } else if is_mouse_over.is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
+    /// Starts a transition when the status changed and keeps
+    /// requesting redraws until it is finished.
+    fn update_transition(
+        &self,
+        state: &mut State,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (Some(duration), Some(now)) = (self.transition, state.now) else {
+            return;
+        };
+
+        let status = self.status(state, cursor);
+        if state.status != Some(status) {
+            if let Some(previous) = state.status {
+                state.transition = Some((previous, now));
+            }
+            state.status = Some(status);
+        }
+
+        if let Some((_, started)) = state.transition {
+            if now.duration_since(started) < duration {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            } else {
+                state.transition = None;
+            }
+        }
+    }
+
+    /// Keeps track of the last interaction and requests the redraws
+    /// needed to fade out the handles.
+    fn update_fade(
+        &self,
+        state: &mut State,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (Some((after, _)), Some(now)) = (self.fade_out, state.now) else {
+            return;
+        };
+        let is_active = state.is_dragging 
+            || find_mouse_over_handle_bounds(&state.hit_bounds, cursor).is_some();
+
+        let last_interaction = match state.last_interaction {
+            Some(last_interaction) if !is_active => last_interaction,
+            _ => now,
+        };
+        state.last_interaction = Some(last_interaction);
+
+        let idle = now.duration_since(last_interaction);
+        if idle < after {
+            shell.request_redraw(window::RedrawRequest::At(last_interaction + after));
+        } else if idle < after + FADE_DURATION {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    /// Scrolls the enclosing scrollable each frame the dragged handle is near
+    /// the edge of the viewport. Returns the position of the drag moved along
+    /// with the content once the scrollable scrolled.
+    fn update_auto_scroll(
+        &self,
+        state: &mut State,
+        event: &Event,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<Point> {
+        let (Some(on_auto_scroll), true) = (&self.on_auto_scroll, state.is_dragging) else {
+            return None;
+        };
+        let (Some(position), Some(viewport)) = (state.drag_position, state.viewport) else {
+            return None;
+        };
+
+        let offset = auto_scroll_offset(self.direction, position, viewport);
+        if offset != 0.0 {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+        let Event::Window(window::Event::RedrawRequested(_)) = event else {
+            return None;
+        };
+        if offset != 0.0 {
+            let offset = match self.direction {
+                Direction::Horizontal => AbsoluteOffset { x: offset, y: 0.0 },
+                Direction::Vertical => AbsoluteOffset { x: 0.0, y: offset },
+            };
+            shell.publish(on_auto_scroll(offset));
+        }
+
+        let scrolled = viewport.position() - state.drag_viewport?.position();
+        (scrolled != Vector::ZERO).then_some(position + scrolled)
+    }
+
+    /// Publishes the long press once the finger was held long enough without
+    /// moving away, which ends the drag it started.
+    fn update_long_press(
+        &self,
+        state: &mut State,
+        event: &Event,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (Some(on_long_press), Some(now)) = (&self.on_long_press, state.now) else {
+            return;
+        };
+
+        match event {
+            Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let has_moved = state.long_press.is_some_and(|(_, pressed_at, _)| {
+                    pressed_at.distance(*position) > DOUBLE_TAP_DISTANCE
+                });
+                if has_moved {
+                    state.long_press = None;
+                }
+            },
+            Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.long_press = None;
+            },
+            _ => {},
+        }
+
+        if let Some((index, position, started)) = state.long_press {
+            if now.duration_since(started) >= self.long_press_duration {
+                shell.publish(on_long_press(index, position));
+                if state.is_dragging {
+                    self.cancel_drag(state, shell);
+                }
+                state.long_press = None;
+                state.end_drag();
+            }
+        }
+    }
+
+    /// The opacity of the handles after fading out.
+    fn opacity(&self, state: &State) -> f32 {
+        let (Some((after, opacity)), Some(now), Some(last_interaction)) = 
+            (self.fade_out, state.now, state.last_interaction) else {
+            return 1.0;
+        };
+        let idle = now.duration_since(last_interaction);
+        if idle <= after {
+            return 1.0;
+        }
+        let progress = 
+            ((idle - after).as_secs_f32() / FADE_DURATION.as_secs_f32()).min(1.0);
+
+        1.0 + (opacity - 1.0) * progress
+    }
+
+    fn publish_hover_change(
+        &self,
+        previous: Option<usize>,
+        hovered: Option<usize>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if previous.is_some() {
+            if let Some(on_hover_exit) = self.on_hover_exit.clone() {
+                shell.publish(on_hover_exit);
+            }
+        }
+        if let (Some(index), Some(on_hover)) = (hovered, &self.on_hover) {
+            shell.publish(on_hover(index));
+        }
+    }
+
+    /// Handles an event for the handles within the given bounds.
+    fn update(
+        &self,
+        state: &mut State,
+        event: Event,
+        total_bounds: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // stores the state
+        self.update_count(state, shell);
+        self.publish_resized(state, shell);
+        self.update_bounds(state, total_bounds);
+        self.check_range(state, shell);
+        state.now = Some(event_time(&event));
+        self.update_transition(state, cursor, shell);
+        self.update_fade(state, cursor, shell);
+        self.update_long_press(state, &event, shell);
+
+        if let Some(position) = self.update_auto_scroll(state, &event, shell) {
+            let moved = Event::Mouse(mouse::Event::CursorMoved { position });
+            return self.update(state, moved, total_bounds, cursor, shell);
+        }
+
+        // the moves outside of the window keep the drag going where the
+        // platform reports them, but the release may be lost out there
+        if state.is_dragging
+            && matches!(event, Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))) {
+            let released = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+            let _ = self.update(state, released, total_bounds, cursor, shell);
+        }
+
+        let is_dragging = state.is_dragging;
+
+        if self.disabled {
+            if is_dragging {
+                self.cancel_drag(state, shell);
+            }
+            state.is_dragging = false;
+            state.focused = None;
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let index = 
+                    find_mouse_over_handle_bounds(
+                        &state.hit_bounds, cursor);
+            
+                // the pressed handle takes the keyboard focus
+                state.focused = index;
+                state.is_focus_visible = false;
+
+                if index.is_some() {
+                    if let (Event::Mouse(_), Some(position)) = (&event, cursor.position()) {
+                        let click = mouse::Click::new(
+                            position, 
+                            mouse::Button::Left, 
+                            state.last_click);
+                        state.last_click = Some(click);
+
+                        if let (mouse::click::Kind::Double, Some(on_autofit)) = 
+                            (click.kind(), &self.on_autofit) {
+                            shell.publish(on_autofit(index.unwrap()));
+                            return event::Status::Captured;
+                        }
+                    }
+                    if let (Event::Touch(touch::Event::FingerPressed { position, .. }), Some(now)) = 
+                        (&event, state.now) {
+                        let is_double_tap = state.last_tap.is_some_and(|(last_position, last_time)| {
+                            now.duration_since(last_time) <= DOUBLE_TAP_INTERVAL
+                                && last_position.distance(*position) <= DOUBLE_TAP_DISTANCE
+                        });
+                        state.last_tap = if is_double_tap { None } else { Some((*position, now)) };
+
+                        if self.on_long_press.is_some() {
+                            state.long_press = Some((index.unwrap(), *position, now));
+                            shell.request_redraw(
+                                window::RedrawRequest::At(now + self.long_press_duration));
+                        }
+
+                        let default = self.default_widths.as_ref()
+                            .and_then(|default_widths| default_widths.get(index.unwrap()));
+                        if let (true, Some(default)) = (is_double_tap, default) {
+                            self.publish_change((index.unwrap(), *default), shell);
+                            return event::Status::Captured;
+                        }
+                    }
+                    state.is_dragging = true;
+                    state.index = index.unwrap();
+                    state.drag_start = self.widths.clone();
+                    state.press_origin = cursor.position();
+                    state.drag_position = cursor.position();
+
+                    let width = self.widths[state.index];
+                    self.publish_drag(state, DragPhase::Started, (state.index, width), width, shell);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { 
+                key: keyboard::Key::Named(key), modifiers, .. }) => {
+                if let Some(index) = state.focused {
+                    let next = next_focus(
+                        index, 
+                        key, 
+                        modifiers, 
+                        state.handle_bounds.len(), 
+                        self.direction);
+
+                    if let Some(next) = next {
+                        state.focused = Some(next);
+                        state.is_focus_visible = true;
+                        return event::Status::Captured;
+                    }
+                    // tabbing past the first or the last handle leaves the divider
+                    if key == keyboard::key::Named::Tab {
+                        state.focused = None;
+                        state.is_focus_visible = false;
+                        return event::Status::Ignored;
+                    }
+                    let step = match (self.direction, key) {
+                        (Direction::Horizontal, keyboard::key::Named::ArrowLeft)
+                        | (Direction::Vertical, keyboard::key::Named::ArrowUp) => -self.keyboard_step,
+                        (Direction::Horizontal, keyboard::key::Named::ArrowRight)
+                        | (Direction::Vertical, keyboard::key::Named::ArrowDown) => self.keyboard_step,
+                        _ => return event::Status::Ignored,
+                    };
+                    state.is_focus_visible = true;
+
+                    if let Some(new_value) = self.nudge(index, step) {
+                        self.publish_change(new_value, shell);
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let (Some(wheel_step), false) = (self.wheel_step, is_dragging) {
+                    let index = 
+                        find_mouse_over_handle_bounds(
+                            &state.hit_bounds, cursor);
+
+                    let ticks = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y,
+                        mouse::ScrollDelta::Pixels { y, .. } => y.signum(),
+                    };
+                    // scrolling down moves the handle right or down
+                    let step = if self.invert_wheel {
+                        ticks * wheel_step
+                    } else {
+                        -ticks * wheel_step
+                    };
+
+                    if let Some(new_value) = index.and_then(|index| self.nudge(index, step)) {
+                        self.publish_change(new_value, shell);
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let index = 
+                    find_mouse_over_handle_bounds(
+                        &state.hit_bounds, cursor);
+
+                if let (Some(index), Some(position)) = (index, cursor.position()) {
+                    if let Some(on_right_click) = &self.on_right_click {
+                        shell.publish(on_right_click(index, position));
+                    }
+                    if self.context_menu.is_some() {
+                        state.menu = Some((index, position));
+                    }
+                    if self.on_right_click.is_some() || self.context_menu.is_some() {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+            | Event::Window(window::Event::Unfocused) => {
+                if is_dragging {
+                    // a lost finger or window focus cancels the drag, the
+                    // release of the button may never come
+                    let is_lost = matches!(
+                        event,
+                        Event::Touch(touch::Event::FingerLost { .. })
+                        | Event::Window(window::Event::Unfocused));
+
+                    // a ghost drag publishes its change once, at the end, and
+                    // a change held back by min_delta is published anyway
+                    let mut widths = self.widths.clone();
+                    if let Some(change) = state.take_released().filter(|_| !is_lost) {
+                        self.publish_dragged(change, shell);
+                        self.apply_dragged(&mut widths, change);
+                    }
+                    if is_lost {
+                        self.cancel_drag(state, shell);
+                    } else if let (Some(width), Some(previous)) =
+                        (widths.get(state.index), state.drag_start.get(state.index).copied()) {
+                        self.publish_drag(state, DragPhase::Ended, (state.index, *width), previous, shell);
+                    }
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    if let Some(on_release_widths) = &self.on_release_widths {
+                        shell.publish(on_release_widths(self.to_published_widths(&widths)));
+                    }
+                    if let (Some(on_release_handle), Some(width)) = 
+                        (&self.on_release_handle, widths.get(state.index)) {
+                        let (index, value) = self.to_published((state.index, *width));
+                        shell.publish(on_release_handle(index, value));
+                    }
+                    state.end_drag();
+                    state.handle_bounds = vec![];
+                    state.width_height_bounds = vec![];
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { id: _, position }) => {
+                if is_dragging {
+                    let position = match (self.clamp_to_viewport, state.viewport) {
+                        (true, Some(viewport)) => self.clamp_to(position, viewport),
+                        _ => position,
+                    };
+                    state.drag_position = Some(position);
+                    state.drag_viewport = state.viewport;
+
+                    // the first move picks the overlapping handle that can move
+                    if state.published.is_none() && state.ghost.is_none() {
+                        state.index = 
+                            movable_handle(&state.handle_bounds, state.index, position, self.direction);
+                    }
+                    if let Some((a, b)) = self.crossing(state, position) {
+                        self.swap(state, (a, b), shell);
+                        return event::Status::Captured;
+                    }
+                    let end_x = total_bounds.x+total_bounds.width;
+                    let end_y = total_bounds.y+total_bounds.height;
+                    let handle_bounds = state.handle_bounds[state.index];
+                    let w_h_bounds = state.width_height_bounds[state.index];
+                    let handle_count = state.handle_bounds.len();
+                    let w_h_count = state.width_height_bounds.len();
+
+                    match self.direction {
+                        Direction::Horizontal => {
+                            if (position.x - handle_bounds.x + handle_bounds.width/2.0).abs() > 0.99 {
+                                let new_value = 
+                                    // Moving left
+                                    if position.x < w_h_bounds.x && state.index == 0 {
+
+                                        state.handle_bounds[state.index].x = w_h_bounds.x;
+                                        (state.index, 0.0)
+                                    } else 
+                                    // Moving left stopping at next divider
+                                    if state.index > 0 && position.x < state.handle_bounds[state.index-1].x {
+
+                                        state.handle_bounds[state.index].x = state.handle_bounds[state.index-1].x;
+                                        (state.index, 0.0)
+                                    } else
+                                    // Moving right: stop at next divider
+                                    if  state.index < handle_count-1 && (state.index < handle_count) && 
+                                        (position.x > state.handle_bounds[state.index+1].x) {
+
+                                        state.handle_bounds[state.index].x = state.handle_bounds[state.index+1].x;
+                                        // the next container collapses
+                                        (state.index, self.widths[state.index] + self.widths[state.index+1])
                                     } else 
                                     // Moving right: last index and no divider at end
                                     if (handle_count < w_h_count) && 
                                         (position.x > end_x-handle_bounds.width/2.0) {
 
                                         state.handle_bounds[state.index].x = end_x-handle_bounds.width/2.0;
-                                        let new_value = (end_x-handle_bounds.width/2.0-w_h_bounds.x).round();
+                                        let new_value = self.quantize(end_x-handle_bounds.width/2.0-w_h_bounds.x);
                                         (state.index, new_value)
                                     }
                                      else {
                                         // moving
                                         state.handle_bounds[state.index].x = position.x;
-                                        let new_value = (position.x - w_h_bounds.x).round();
+                                        let new_value = 
+                                            self.quantize(position.x - w_h_bounds.x - self.gap(state.index)/2.0);
                                         (state.index, new_value)
                                     };
-                                
-                                shell.publish((self.on_change)(new_value));
+                            
+                                let unclamped = self.snap(state, self.shape(new_value));
+                                let new_value = self.round(self.clamp_total(unclamped));
+                                let new_value = keep_order(&self.widths, new_value);
+                                let new_value = keep_minimums(&state.minimums, &state.drag_start, new_value);
+                                self.publish_limit(state, self.round(unclamped).1, new_value, shell);
+                                self.track_hidden(state, new_value, shell);
+                                self.publish_snap(state, new_value, shell);
+                                self.publish_step(state, new_value, shell);
+
+                                if self.exceeds_min_delta(state, new_value) {
+                                    let previous = state.published
+                                        .or(state.drag_start.get(new_value.0).copied())
+                                        .unwrap_or(new_value.1);
+                                    self.publish_drag(state, DragPhase::Moved, new_value, previous, shell);
+                                    state.published = Some(new_value.1);
+                                    if self.ghost {
+                                        state.ghost = Some(new_value);
+                                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                                    } else {
+                                        self.publish_dragged(new_value, shell);
+                                    }
+                                    state.held_back = None;
+                                } else {
+                                    state.held_back = Some(new_value);
+                                }
                                 return event::Status::Captured;
                             }
                         },
@@ -417,35 +2137,666 @@ where
                                         (position.y > state.handle_bounds[state.index+1].y) {
 
                                         state.handle_bounds[state.index].y = state.handle_bounds[state.index+1].y;
-                                        (state.index, 0.0)
+                                        // the next container collapses
+                                        (state.index, self.widths[state.index] + self.widths[state.index+1])
                                     } else 
                                     // Moving right: last index and no divider at end
                                     if (handle_count < w_h_count) && 
                                         (position.y > end_y-handle_bounds.height/2.0) {
-                                            
+                                        
                                         state.handle_bounds[state.index].y = end_y-handle_bounds.height/2.0;
-                                        let new_value = (end_y-handle_bounds.height/2.0-w_h_bounds.y).round();
+                                        let new_value = self.quantize(end_y-handle_bounds.height/2.0-w_h_bounds.y);
                                         (state.index, new_value)
                                     }
                                      else {
                                         // moving
                                         state.handle_bounds[state.index].y = position.y;
-                                        let new_value = (position.y - w_h_bounds.y).round();
+                                        let new_value = 
+                                            self.quantize(position.y - w_h_bounds.y - self.gap(state.index)/2.0);
                                         (state.index, new_value)
                                     };
-                                
-                                shell.publish((self.on_change)(new_value));
+                            
+                                let unclamped = self.snap(state, self.shape(new_value));
+                                let new_value = self.round(self.clamp_total(unclamped));
+                                let new_value = keep_order(&self.widths, new_value);
+                                let new_value = keep_minimums(&state.minimums, &state.drag_start, new_value);
+                                self.publish_limit(state, self.round(unclamped).1, new_value, shell);
+                                self.track_hidden(state, new_value, shell);
+                                self.publish_snap(state, new_value, shell);
+                                self.publish_step(state, new_value, shell);
+
+                                if self.exceeds_min_delta(state, new_value) {
+                                    let previous = state.published
+                                        .or(state.drag_start.get(new_value.0).copied())
+                                        .unwrap_or(new_value.1);
+                                    self.publish_drag(state, DragPhase::Moved, new_value, previous, shell);
+                                    state.published = Some(new_value.1);
+                                    if self.ghost {
+                                        state.ghost = Some(new_value);
+                                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                                    } else {
+                                        self.publish_dragged(new_value, shell);
+                                    }
+                                    state.held_back = None;
+                                } else {
+                                    state.held_back = Some(new_value);
+                                }
                                 return event::Status::Captured;
                             }
                         },
                     }
+                } else {
+                    let hovered = 
+                        find_mouse_over_handle_bounds(
+                            &state.hit_bounds, cursor);
+
+                    if hovered != state.hovered {
+                        self.publish_hover_change(state.hovered, hovered, shell);
+                        state.hovered = hovered;
+                    }
+                }
+            },
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                // a drag goes on outside of the window until the release
+                if !is_dragging && state.hovered.is_some() {
+                    self.publish_hover_change(state.hovered, None, shell);
+                    state.hovered = None;
+                }
+            },
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw_handles(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        cursor: mouse::Cursor,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let status = self.status(state, cursor);
+        let style = self.resolve_style(state, status, |status| theme.style(&self.class, status));
+
+        let active_index = if state.is_dragging {
+            Some(state.index)
+        } else {
+            find_mouse_over_handle_bounds(&state.hit_bounds, cursor)
+        };
+
+        if let (Some(marks), Some(tick), Some(index)) = (&self.ticks, style.ticks, active_index) {
+            self.draw_ticks(state, renderer, marks, tick, index);
+        }
+        if let Some(index) = active_index {
+            self.draw_marks(state, renderer, style.mark_color, index);
+        }
+        if state.is_dragging {
+            self.draw_step_label(state, renderer, theme, cursor);
+        }
+
+        for i in 0..self.widths.len() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: state.width_height_bounds[i],
+                    ..renderer::Quad::default()
+                },
+                Background::Color(Color::TRANSPARENT),
+            );
+            // fill with the handle
+            if !self.include_last_handle && i == self.widths.len()-1{
+                break;
+            }
+            let style = match &self.indexed_style {
+                Some(indexed_style) => 
+                    self.resolve_style(state, status, |status| indexed_style(theme, status, i)),
+                None => style,
+            };
+            if self.track_only {
+                let track = match self.direction {
+                    Direction::Horizontal => Rectangle {
+                        x: state.handle_bounds[i].center_x() - TRACK_WIDTH/2.0,
+                        width: TRACK_WIDTH,
+                        ..state.hit_bounds[i]
+                    },
+                    Direction::Vertical => Rectangle {
+                        y: state.handle_bounds[i].center_y() - TRACK_WIDTH/2.0,
+                        height: TRACK_WIDTH,
+                        ..state.hit_bounds[i]
+                    },
+                };
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+                continue;
+            }
+            // only the drawing gets thicker, the hit area stays the same
+            let bounds = match (style.hovered_thickness, active_index == Some(i)) {
+                (Some(thickness), true) => {
+                    thicken(state.handle_bounds[i], thickness, self.direction)
+                },
+                _ => state.handle_bounds[i],
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style.border_radius,
+                        width: style.border_width,
+                        color: style.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            if let (Some(ring), true, Some(focused)) = 
+                (style.focus_ring, state.is_focus_visible, state.focused) {
+                if focused == i {
+                    let spread = ring.offset + ring.width;
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x - spread,
+                                y: bounds.y - spread,
+                                width: bounds.width + spread * 2.0,
+                                height: bounds.height + spread * 2.0,
+                            },
+                            border: Border {
+                                radius: style.border_radius,
+                                width: ring.width,
+                                color: ring.color,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Background::Color(Color::TRANSPARENT),
+                    );
+                }
+            }
+        }
+
+        if let (true, true, Some(handle)) = 
+            (self.guides, state.is_dragging, state.handle_bounds.get(state.index)) {
+            self.draw_guide(state, renderer, handle.center(), style.guide_color);
+        }
+        if let (true, true, Some(position)) = 
+            (self.crosshair, state.is_dragging, cursor.position()) {
+            self.draw_guide(state, renderer, position, style.guide_color);
+        }
+    }
+
+    /// Draws a line across the whole [`Divider`] through the given point.
+    fn draw_guide<Renderer>(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        at: Point,
+        color: Color,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        let Some(area) = state.width_height_bounds.iter()
+            .copied()
+            .reduce(|area, bounds| area.union(&bounds)) else {
+            return;
+        };
+
+        let bounds = match self.direction {
+            Direction::Horizontal => Rectangle {
+                x: at.x - GUIDE_WIDTH/2.0,
+                y: area.y,
+                width: GUIDE_WIDTH,
+                height: area.height,
+            },
+            Direction::Vertical => Rectangle {
+                x: area.x,
+                y: at.y - GUIDE_WIDTH/2.0,
+                width: area.width,
+                height: GUIDE_WIDTH,
+            },
+        };
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            Background::Color(color),
+        );
+    }
+
+    /// Resolves the style for the status, in transition from the previous
+    /// status and faded out.
+    fn resolve_style(
+        &self,
+        state: &State,
+        status: Status,
+        style: impl Fn(Status) -> Style,
+    ) -> Style {
+        let resolved = match (state.transition, state.now, self.transition) {
+            (Some((from, started)), Some(now), Some(duration)) => {
+                let progress = 
+                    now.duration_since(started).as_secs_f32() / duration.as_secs_f32();
+                mix_style(style(from), style(status), progress.clamp(0.0, 1.0))
+            },
+            _ => style(status),
+        };
+        fade_style(resolved, self.opacity(state))
+    }
+
+    /// Draws the tick marks along the track of the handle at index,
+    /// from the start of its container.
+    fn draw_ticks<Renderer>(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        marks: &TickMarks,
+        tick: TickStyle,
+        index: usize,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        let Some((handle, container, span)) = self.track(state, index) else {
+            return;
+        };
+
+        for value in tick_values(marks, &self.steps, span) {
+            let offset = value + self.gap(index)/2.0 - tick.width/2.0;
+            let bounds = match self.direction {
+                Direction::Horizontal => Rectangle {
+                    x: container.x + offset,
+                    y: handle.y,
+                    width: tick.width,
+                    height: tick.length,
+                },
+                Direction::Vertical => Rectangle {
+                    x: handle.x,
+                    y: container.y + offset,
+                    width: tick.length,
+                    height: tick.width,
+                },
+            };
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                Background::Color(tick.color),
+            );
+        }
+    }
+
+    /// Draws the labels of the marks along the track of the handle at index.
+    fn draw_marks<Renderer>(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        color: Color,
+        index: usize,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let Some((handle, container, span)) = self.track(state, index) else {
+            return;
+        };
+        let clip = state.width_height_bounds.iter()
+            .fold(container, |area, bounds| area.union(bounds));
+        let size = renderer.default_size() * MARK_TEXT_SCALE;
+
+        let marks = self.marks.iter()
+            .filter(|(value, _)| *value >= 0.0 && *value <= span);
+
+        for (value, label) in marks {
+            let offset = value + self.gap(index)/2.0;
+            let (position, horizontal_alignment, vertical_alignment) = match self.direction {
+                Direction::Horizontal => (
+                    Point::new(container.x + offset, handle.y),
+                    alignment::Horizontal::Center,
+                    alignment::Vertical::Top,
+                ),
+                Direction::Vertical => (
+                    Point::new(handle.x, container.y + offset),
+                    alignment::Horizontal::Left,
+                    alignment::Vertical::Center,
+                ),
+            };
+            renderer.fill_text(
+                Text {
+                    content: label.clone(),
+                    bounds: Size::INFINITY,
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                },
+                position,
+                color,
+                clip,
+            );
+        }
+    }
+
+    /// Draws the label of the labeled step nearest to the cursor
+    /// next to the dragged handle.
+    fn draw_step_label<Renderer>(
+        &self,
+        state: &State,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        cursor: mouse::Cursor,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let (Some(position), Some((handle, container, _))) = 
+            (cursor.position(), self.track(state, state.index)) else {
+            return;
+        };
+        let value = match self.direction {
+            Direction::Horizontal => position.x - container.x,
+            Direction::Vertical => position.y - container.y,
+        } - self.gap(state.index)/2.0;
+
+        let nearest = self.step_labels.iter()
+            .filter(|(step, _)| self.steps.contains(step))
+            .filter(|(step, _)| (step - value).abs() <= STEP_LABEL_DISTANCE)
+            .min_by(|(a, _), (b, _)| (a - value).abs().total_cmp(&(b - value).abs()));
+        let Some((_, label)) = nearest else {
+            return;
+        };
+
+        let style = theme.menu_style();
+        let text_size = renderer.default_size() * MARK_TEXT_SCALE;
+        let size = Renderer::Paragraph::with_text(Text {
+            content: label.as_str(),
+            bounds: Size::INFINITY,
+            size: text_size,
+            line_height: text::LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+        })
+        .min_bounds();
+
+        // beside the handle, at its start
+        let bounds = Rectangle {
+            x: handle.x + handle.width + MENU_PADDING,
+            y: handle.y + MENU_PADDING,
+            width: size.width + MENU_PADDING * 2.0,
+            height: size.height + MENU_PADDING * 2.0,
+        };
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+        renderer.fill_text(
+            Text {
+                content: label.clone(),
+                bounds: size,
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+            },
+            Point::new(bounds.x + MENU_PADDING, bounds.y + MENU_PADDING),
+            style.text_color,
+            bounds,
+        );
+    }
+
+    /// The bounds of the handle at index, of its container and the span
+    /// of its track, the two containers it separates or the rest of the
+    /// divider for the last handle.
+    fn track(&self, state: &State, index: usize) -> Option<(Rectangle, Rectangle, f32)> {
+        let handle = state.handle_bounds.get(index)?;
+        let container = state.width_height_bounds.get(index)?;
+        let area = state.width_height_bounds.iter()
+            .fold(*container, |area, bounds| area.union(bounds));
+
+        let span = match self.widths.get(index+1) {
+            Some(next) => self.widths[index] + next,
+            None => match self.direction {
+                Direction::Horizontal => area.x + area.width - container.x,
+                Direction::Vertical => area.y + area.height - container.y,
+            },
+        };
+
+        Some((*handle, *container, span))
+    }
+
+    fn interaction(&self, state: &State, cursor: mouse::Cursor) -> mouse::Interaction {
+        let is_mouse_over = 
+            find_mouse_over_handle_bounds(
+                &state.hit_bounds,  
+                cursor);
+
+        if self.disabled {
+            mouse::Interaction::default()
+        } else if state.is_dragging || is_mouse_over.is_some(){
+            match self.direction {
+                Direction::Horizontal => mouse::Interaction::ResizingHorizontally,
+                Direction::Vertical => mouse::Interaction::ResizingVertically,
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    /// Computes the bounds of all the handles of the [`Divider`] laid out in the
+    /// given bounds, the same way the widget does, without a renderer.
+    pub fn handle_rects(&self, bounds: Rectangle) -> Vec<Rectangle> {
+        let mut state = State::default();
+        self.update_bounds(&mut state, bounds);
+
+        state.handle_bounds
+    }
+
+    /// Computes the bounds of the handle at index, see [`Divider::handle_rects`].
+    pub fn handle_rect(&self, index: usize, bounds: Rectangle) -> Option<Rectangle> {
+        self.handle_rects(bounds).get(index).copied()
+    }
+
+    /// Sets the style of the [`Divider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of each handle of the [`Divider`] from its index, to
+    /// emphasize some of them like group boundaries. It takes precedence over
+    /// the style and the class for the handles, which still style the guides,
+    /// ticks and marks.
+    #[must_use]
+    pub fn indexed_style(mut self, style: impl Fn(&Theme, Status, usize) -> Style + 'a) -> Self {
+        self.indexed_style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the style class of the [`Divider`] to the default class of
+    /// the [`Role`] in the theme.
+    #[must_use]
+    pub fn role(mut self, role: Role) -> Self {
+        self.class = Theme::role(role);
+        self
+    }
+
+    /// Sets the style class of the [`Divider`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The widget of a [`Divider`], its content kept apart, so the overlay
+/// of the content and the one of the handles can be borrowed together.
+struct Wrapper<'a, Message, Theme, Renderer, F>
+where
+    Theme: Catalog,
+{
+    divider: Divider<'a, Message, Theme, Renderer, F>,
+    content: Option<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer, F> Widget<Message, Theme, Renderer>
+    for Wrapper<'a, Message, Theme, Renderer, F>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+    F: Fn((usize, f32)) -> Message,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if let Some(content) = &self.content {
+            tree.diff_children(std::slice::from_ref(content));
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        match &self.content {
+            Some(content) => content.as_widget().size(),
+            None => Size {
+                width: self.divider.width,
+                height: Length::Shrink,
+            },
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match &self.content {
+            Some(content) => {
+                if self.divider.content_minimums {
+                    measure::request_natural_sizes(&mut tree.children[0]);
+                }
+                // the handles cover the content
+                let node = content.as_widget().layout(&mut tree.children[0], renderer, limits);
+
+                if self.divider.content_minimums {
+                    let minimums = measure::natural_sizes(&tree.children[0])
+                        .into_iter()
+                        .map(|size| match self.divider.direction {
+                            Direction::Horizontal => size.width,
+                            Direction::Vertical => size.height,
+                        })
+                        .collect();
+                    tree.state.downcast_mut::<State>().minimums = minimums;
                 }
+                self.divider.update_extent(tree.state.downcast_mut::<State>(), node.size());
+
+                layout::Node::with_children(node.size(), vec![node])
+            },
+            None => {
+                let node = layout::atomic(limits, self.divider.width, self.divider.height);
+                self.divider.update_extent(tree.state.downcast_mut::<State>(), node.size());
+                node
             },
-            _ => {}
         }
+    }
 
-        event::Status::Ignored
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        // an overlay keeps its bounds translated to the window
+        if !state.handles_overlaid {
+            self.divider.update_bounds(state, layout.bounds());
+        }
+
+        state.default_widths = self.divider.default_widths.as_ref()
+            .map(|default_widths| self.divider.to_unit_widths(default_widths));
+        state.weighted_widths = self.divider.weights.as_ref().map(|weights| {
+            let widths = resize::from_weights(weights, self.divider.widths.iter().sum());
+            self.divider.to_unit_widths(&widths)
+        });
+
+        operation.custom(state, self.divider.id.as_ref());
+
+        if let (Some(content), Some(content_layout)) = (&self.content, layout.children().next()) {
+            operation.container(self.divider.id.as_ref(), layout.bounds(), &mut |operation| {
+                content.as_widget().operate(&mut tree.children[0], content_layout, renderer, operation);
+            });
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        state.viewport = Some(*viewport);
+
+        if !state.handles_overlaid {
+            let status = self.divider.update(state, event.clone(), layout.bounds(), cursor, shell);
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
 
+        match (&mut self.content, layout.children().next()) {
+            (Some(content), Some(content_layout)) => content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+            _ => event::Status::Ignored,
+        }
     }
     
     fn draw(
@@ -453,97 +2804,382 @@ where
         tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let (Some(content), Some(content_layout)) = (&self.content, layout.children().next()) {
+            content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        if !state.handles_overlaid {
+            self.divider.draw_handles(state, renderer, theme, cursor);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.handles_overlaid {
+            let interaction = self.divider.interaction(state, cursor);
+            if interaction != mouse::Interaction::default() {
+                return interaction;
+            }
+        }
+
+        match (&self.content, layout.children().next()) {
+            (Some(content), Some(content_layout)) => content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content_layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree { state, children, .. } = tree;
+        let state = state.downcast_mut::<State>();
+        state.handles_overlaid = false;
+
+        if let Some((index, position)) = state.menu {
+            let entries = self.divider.context_menu.as_ref()
+                .map(|context_menu| context_menu(index))
+                .unwrap_or_default();
+
+            if !entries.is_empty() {
+                return Some(overlay::Element::new(Box::new(ContextMenu {
+                    state,
+                    entries,
+                    position: position + translation,
+                })));
+            }
+        }
+
+        if let Some(content) = &mut self.content {
+            let content_layout = layout.children().next()?;
+
+            // an open overlay of the content, like a pick list, comes first
+            let content_overlay = content.as_widget_mut()
+                .overlay(&mut children[0], content_layout, renderer, translation);
+
+            if content_overlay.is_some() {
+                return content_overlay;
+            }
+        } else if !(state.is_dragging && (self.divider.ghost || !self.divider.step_labels.is_empty())) {
+            // without content, only the preview of a ghost drag and the labels
+            // of the steps are overlaid, so a clipping parent doesn't hide them
+            return None;
+        }
+
+        state.handles_overlaid = true;
+
+        Some(overlay::Element::new(Box::new(HandlesOverlay {
+            divider: &self.divider,
+            state,
+            bounds: layout.bounds() + translation,
+        })))
+    }
+}
+
+/// Draws the handles of a [`Divider`] with content, or of a drag showing
+/// a ghost or step labels, above everything else, so they are neither
+/// covered by other widgets nor clipped by containers.
+struct HandlesOverlay<'a, 'b, Message, Theme, Renderer, F>
+where
+    Theme: Catalog,
+{
+    divider: &'b Divider<'a, Message, Theme, Renderer, F>,
+    state: &'b mut State,
+    bounds: Rectangle,
+}
+
+impl<Message, Theme, Renderer, F> overlay::Overlay<Message, Theme, Renderer>
+    for HandlesOverlay<'_, '_, Message, Theme, Renderer, F>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+    F: Fn((usize, f32)) -> Message,
+{
+    fn layout(&mut self, _renderer: &Renderer, _bounds: Size) -> layout::Node {
+        self.divider.update_bounds(self.state, self.bounds);
+
+        layout::Node::new(self.bounds.size()).move_to(self.bounds.position())
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
         _style: &renderer::Style,
         _layout: Layout<'_>,
         cursor: mouse::Cursor,
+    ) {
+        self.divider.draw_handles(self.state, renderer, theme, cursor);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.divider.update(self.state, event, self.bounds, cursor, shell)
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.divider.interaction(self.state, cursor)
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        // only the handles block the content below
+        self.state.is_dragging
+            || self.state.hit_bounds.iter().any(|bounds| bounds.contains(cursor_position))
+    }
+}
+
+const MENU_PADDING: f32 = 5.0;
+
+/// The context menu of a [`Divider`] handle.
+struct ContextMenu<'b, Message> {
+    state: &'b mut State,
+    entries: Vec<(String, Message)>,
+    position: Point,
+}
+
+impl<Message> ContextMenu<'_, Message> {
+    fn entry_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let entry_height = bounds.height / self.entries.len() as f32;
+        Rectangle {
+            x: bounds.x,
+            y: bounds.y + entry_height * index as f32,
+            width: bounds.width,
+            height: entry_height,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for ContextMenu<'_, Message>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size = renderer.default_size();
+        let entry_height = 
+            text::LineHeight::default().to_absolute(text_size).0 + MENU_PADDING * 2.0;
+
+        let label_width = self.entries.iter().map(|(label, _)| {
+            Renderer::Paragraph::with_text(Text {
+                content: label.as_str(),
+                bounds: Size::INFINITY,
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+            })
+            .min_bounds()
+            .width
+        })
+        .fold(0.0, f32::max);
+
+        let size = Size::new(
+            label_width + MENU_PADDING * 2.0, 
+            entry_height * self.entries.len() as f32);
+
+        // keep the menu inside the window
+        let position = Point::new(
+            self.position.x.min(bounds.width - size.width).max(0.0),
+            self.position.y.min(bounds.height - size.height).max(0.0),
+        );
+
+        layout::Node::new(size).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
     ) {
-        let state = tree.state.downcast_ref::<State>();
-        let is_mouse_over = 
-            find_mouse_over_handle_bounds(
-                &state.handle_bounds,
-                cursor,);
-        
-        let status = if state.is_dragging {
-            Status::Dragged
-        } else if is_mouse_over.is_some() {
-            Status::Hovered
-        } else {
-            Status::Active
-        };
+        let bounds = layout.bounds();
+        let style = theme.menu_style();
 
-        let style = theme.style(&self.class, status);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
 
-        for i in 0..self.widths.len() {
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: state.width_height_bounds[i],
-                    ..renderer::Quad::default()
-                },
-                Background::Color(Color::TRANSPARENT),
-            );
-            // fill with the handle
-            if !self.include_last_handle && i == self.widths.len()-1{
-                break;
-            }
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: state.handle_bounds[i],
-                    border: Border {
-                        radius: style.border_radius,
-                        width: style.border_width,
-                        color: style.border_color,
+        for (i, (label, _)) in self.entries.iter().enumerate() {
+            let entry_bounds = self.entry_bounds(bounds, i);
+            let is_selected = cursor.is_over(entry_bounds);
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: entry_bounds,
+                        ..renderer::Quad::default()
                     },
-                    ..renderer::Quad::default()
+                    style.selected_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: label.clone(),
+                    bounds: Size::new(
+                        entry_bounds.width - MENU_PADDING * 2.0, 
+                        entry_bounds.height),
+                    size: renderer.default_size(),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
                 },
-                style.background,
+                Point::new(entry_bounds.x + MENU_PADDING, entry_bounds.center_y()),
+                if is_selected { style.selected_text_color } else { style.text_color },
+                bounds,
             );
         }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let bounds = layout.bounds();
+                // any press closes the menu, only presses on the menu are captured
+                self.state.menu = None;
+
+                if cursor.is_over(bounds) {
+                    let selected = (0..self.entries.len())
+                        .find(|i| cursor.is_over(self.entry_bounds(bounds, *i)));
+
+                    if let Some(i) = selected {
+                        shell.publish(self.entries[i].1.clone());
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.state.menu = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
 
+        event::Status::Ignored
     }
 
     fn mouse_interaction(
         &self,
-        tree: &Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        let state = tree.state.downcast_ref::<State>();
-        let is_mouse_over = 
-            find_mouse_over_handle_bounds(
-                &state.handle_bounds,  
-                cursor);
-
-        if state.is_dragging || is_mouse_over.is_some(){
-            match self.direction {
-                Direction::Horizontal => mouse::Interaction::ResizingHorizontally,
-                Direction::Vertical => mouse::Interaction::ResizingVertically,
-            }
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
         }
     }
 }
 
-impl<'a, Message, Theme, Renderer> From<Divider<'a, Message, Theme>>
+impl<'a, Message, Theme, Renderer, F> From<Divider<'a, Message, Theme, Renderer, F>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: iced::advanced::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
+    F: Fn((usize, f32)) -> Message + 'a,
 {
     fn from(
-        divider: Divider<'a, Message, Theme>,
+        mut divider: Divider<'a, Message, Theme, Renderer, F>,
     ) -> Element<'a, Message, Theme, Renderer> {
-        Element::new(divider)
+        let content = divider.content.take();
+
+        Element::new(Wrapper { divider: divider.into_pixels(), content })
     }
 }
 
+/// Converts the sizes in units of the containers to pixels, from their
+/// positions along the [`Divider`].
+fn sizes_to_pixels(sizes: &[f32], origin: f32, from_value: &dyn Fn(f32) -> f32) -> Vec<f32> {
+    let mut position = origin;
+    sizes.iter()
+        .map(|size| {
+            let start = from_value(position);
+            position += size;
+            from_value(position) - start
+        })
+        .collect()
+}
+
 fn get_handle_bounds(
     bounds: Rectangle,
     widths_heights: &[f32],
+    gaps: &[f32],
     handle_width: f32,
     handle_height: f32,
     handle_offsets: &[f32],
@@ -558,6 +3194,8 @@ fn get_handle_bounds(
         };
  
     for (i, width_height) in widths_heights.iter().enumerate() {
+        // the handle sits in the middle of the gap
+        let gap = gaps.get(i).copied().unwrap_or(0.0);
         
         if i == widths_heights.len()-1 {
             if include_last_handle {
@@ -572,7 +3210,7 @@ fn get_handle_bounds(
         let rect = match direction {
             Direction::Horizontal => {
                 Rectangle{ 
-                    x: start+handle_offsets[i], 
+                    x: start+gap/2.0+handle_offsets[i], 
                     y: bounds.y, 
                     width: handle_width, 
                     height: handle_height,
@@ -581,7 +3219,7 @@ fn get_handle_bounds(
             Direction::Vertical => {
                 Rectangle{
                     x: bounds.x,
-                    y: start+handle_offsets[i],
+                    y: start+gap/2.0+handle_offsets[i],
                     width: handle_width,
                     height: handle_height,
                 }
@@ -589,6 +3227,7 @@ fn get_handle_bounds(
         };
             
         handle_bounds.push(rect);
+        start += gap;
 
     }
     handle_bounds
@@ -597,6 +3236,7 @@ fn get_handle_bounds(
 fn get_width_height_bounds(
     bounds: Rectangle,
     widths_heights: &[f32],
+    gaps: &[f32],
     handle_width: f32,
     handle_height: f32,
     direction: Direction,
@@ -607,7 +3247,7 @@ fn get_width_height_bounds(
             Direction::Horizontal => bounds.x,
             Direction::Vertical => bounds.y,
         };
-        for width_height in widths_heights.iter() {
+        for (i, width_height) in widths_heights.iter().enumerate() {
             let rect = match direction {
                 Direction::Horizontal => {
                     Rectangle{ 
@@ -629,33 +3269,303 @@ fn get_width_height_bounds(
                 
             w_h_bounds.push(rect);
 
-            match direction {
-                Direction::Horizontal => {
-                    start += width_height;
-                },
-                Direction::Vertical => {
-                    start += width_height;
-                },
-            }
-            
+            start += width_height + gaps.get(i).copied().unwrap_or(0.0);
+            
+        }
+        w_h_bounds
+}
+
+fn thicken(bounds: Rectangle, thickness: f32, direction: Direction) -> Rectangle {
+    match direction {
+        Direction::Horizontal => Rectangle {
+            x: bounds.center_x() - thickness/2.0,
+            width: thickness,
+            ..bounds
+        },
+        Direction::Vertical => Rectangle {
+            y: bounds.center_y() - thickness/2.0,
+            height: thickness,
+            ..bounds
+        },
+    }
+}
+
+/// Moves the bounds after the frozen containers back by the offset and
+/// hides the handles scrolled under the frozen containers.
+fn scroll_bounds(
+    handle_bounds: &mut [Rectangle],
+    width_height_bounds: &mut [Rectangle],
+    frozen: usize,
+    offset: f32,
+    direction: Direction,
+) {
+    let frozen_end = match (frozen.checked_sub(1), direction) {
+        (Some(last), Direction::Horizontal) => handle_bounds.get(last).map(|bounds| bounds.x + bounds.width),
+        (Some(last), Direction::Vertical) => handle_bounds.get(last).map(|bounds| bounds.y + bounds.height),
+        (None, Direction::Horizontal) => width_height_bounds.first().map(|bounds| bounds.x),
+        (None, Direction::Vertical) => width_height_bounds.first().map(|bounds| bounds.y),
+    };
+    let Some(frozen_end) = frozen_end else {
+        return;
+    };
+
+    for bounds in width_height_bounds.iter_mut().skip(frozen) {
+        match direction {
+            Direction::Horizontal => bounds.x -= offset,
+            Direction::Vertical => bounds.y -= offset,
+        }
+    }
+
+    for bounds in handle_bounds.iter_mut().skip(frozen) {
+        match direction {
+            Direction::Horizontal => {
+                bounds.x -= offset;
+                if bounds.x < frozen_end {
+                    bounds.width = 0.0;
+                }
+            },
+            Direction::Vertical => {
+                bounds.y -= offset;
+                if bounds.y < frozen_end {
+                    bounds.height = 0.0;
+                }
+            },
+        }
+    }
+}
+
+/// Finds the handle taking the focus from Tab, Shift+Tab or Ctrl with the arrows.
+/// Tab past the last handle returns None to let the focus leave the divider.
+fn next_focus(
+    index: usize,
+    key: keyboard::key::Named,
+    modifiers: keyboard::Modifiers,
+    count: usize,
+    direction: Direction,
+) -> Option<usize> {
+    use keyboard::key::Named;
+
+    match (key, direction) {
+        (Named::Tab, _) if modifiers.shift() => index.checked_sub(1),
+        (Named::Tab, _) => (index+1 < count).then_some(index+1),
+        (Named::ArrowLeft, Direction::Horizontal)
+        | (Named::ArrowUp, Direction::Vertical) if modifiers.control() => {
+            Some(index.saturating_sub(1))
+        },
+        (Named::ArrowRight, Direction::Horizontal)
+        | (Named::ArrowDown, Direction::Vertical) if modifiers.control() => {
+            Some((index+1).min(count.saturating_sub(1)))
+        },
+        _ => None,
+    }
+}
+
+/// Snaps the value, unless the previous value is reached by snapping a value
+/// within the hysteresis of it, so a value at the point where it snaps
+/// to another one doesn't flicker between both.
+fn keep_snapped(value: f32, previous: f32, hysteresis: f32, snap: impl Fn(f32) -> f32) -> f32 {
+    let snapped = snap(value);
+    if hysteresis > 0.0 && snapped != previous
+        && (snap(value - hysteresis) == previous || snap(value + hysteresis) == previous) {
+        previous
+    } else {
+        snapped
+    }
+}
+
+/// Snaps the container of the handle, or the one after it, to zero
+/// when the change makes it smaller than the threshold.
+fn snap_collapsed(widths: &[f32], threshold: f32, (index, value): (usize, f32)) -> (usize, f32) {
+    if value < threshold {
+        return (index, 0.0);
+    }
+    match widths.get(index+1) {
+        Some(next) if widths[index] + next - value < threshold => (index, widths[index] + next),
+        _ => (index, value),
+    }
+}
+
+/// The values of the tick marks along a track of the given span.
+fn tick_values(marks: &TickMarks, steps: &[f32], span: f32) -> Vec<f32> {
+    match marks {
+        TickMarks::Steps => steps.iter()
+            .copied()
+            .filter(|step| *step >= 0.0 && *step <= span)
+            .collect(),
+        TickMarks::Every(interval) if *interval > 0.0 && span.is_finite() => {
+            (1..)
+                .map(|i| i as f32 * interval)
+                .take_while(|value| *value <= span)
+                .collect()
+        },
+        TickMarks::Every(_) => vec![],
+    }
+}
+
+/// The offsets centering the handles on the edges of the containers,
+/// the last one pulled in to keep it in bounds.
+fn default_offsets(count: usize, thickness: f32) -> Vec<f32> {
+    anchor_offsets(count, thickness, Anchor::Center)
+}
+
+/// The offsets placing the handles on the edges of the containers by the
+/// anchor, the last one pulled in to keep it in bounds.
+fn anchor_offsets(count: usize, thickness: f32, anchor: Anchor) -> Vec<f32> {
+    let offset = match anchor {
+        Anchor::Start => 0.0,
+        Anchor::Center => -thickness/2.0,
+        Anchor::End => -thickness,
+    };
+    let mut handle_offsets = vec![offset; count.saturating_sub(1)];
+    handle_offsets.extend([-thickness]);
+    handle_offsets
+}
+
+/// The areas the handles are pressed in, the handles widened towards the
+/// side they can move to when a container next to them is collapsed, so
+/// they aren't stuck behind a sliver.
+fn bias_hit_bounds(
+    handle_bounds: &[Rectangle],
+    widths: &[f32],
+    direction: Direction,
+) -> Vec<Rectangle> {
+    handle_bounds.iter().enumerate().map(|(i, bounds)| {
+        let is_pinned_start = widths.get(i).is_some_and(|width| *width <= 0.0);
+        let is_pinned_end = widths.get(i+1).is_some_and(|width| *width <= 0.0);
+
+        let (before, after) = match (is_pinned_start, is_pinned_end) {
+            (true, false) => (0.0, HIT_BIAS),
+            (false, true) => (HIT_BIAS, 0.0),
+            _ => return *bounds,
+        };
+        match direction {
+            Direction::Horizontal => Rectangle {
+                x: bounds.x - before,
+                width: bounds.width + before + after,
+                ..*bounds
+            },
+            Direction::Vertical => Rectangle {
+                y: bounds.y - before,
+                height: bounds.height + before + after,
+                ..*bounds
+            },
         }
-        w_h_bounds
+    })
+    .collect()
+}
+
+/// The change moving the handle paired with the changed one back by as much,
+/// once the change is applied. Handles at the same distance from the center
+/// are paired, the last handle and the middle one of an even count are not.
+fn mirrored(widths: &[f32], (index, value): (usize, f32)) -> Option<(usize, f32)> {
+    let paired = widths.len().checked_sub(2)?.checked_sub(index)?;
+    if paired == index {
+        return None;
+    }
+    let diff = value - widths[index];
+
+    let mut widths = widths.to_vec();
+    resize::apply(&mut widths, (index, value));
+
+    let max = widths[paired] + widths[paired+1];
+    Some((paired, (widths[paired] - diff).clamp(0.0, max)))
+}
+
+/// Stretches the bounds of a handle across the whole area.
+fn full_length(bounds: Rectangle, area: Rectangle, direction: Direction) -> Rectangle {
+    match direction {
+        Direction::Horizontal => Rectangle {
+            y: area.y,
+            height: area.height,
+            ..bounds
+        },
+        Direction::Vertical => Rectangle {
+            x: area.x,
+            width: area.width,
+            ..bounds
+        },
+    }
+}
+
+/// Keeps the handle between its neighbors, so the container of the handle
+/// and the one after it never become negative.
+fn keep_order(widths: &[f32], (index, value): (usize, f32)) -> (usize, f32) {
+    let value = value.max(0.0);
+    match widths.get(index+1) {
+        Some(next) => (index, value.min((widths[index] + next).max(0.0))),
+        None => (index, value),
+    }
+}
+
+/// Keeps the container of the handle and the one after it at least at their
+/// minimums, or at their size at the start of the drag when it is smaller.
+fn keep_minimums(minimums: &[f32], widths: &[f32], (index, value): (usize, f32)) -> (usize, f32) {
+    let minimum = |i: usize| {
+        match (minimums.get(i), widths.get(i)) {
+            (Some(minimum), Some(width)) => minimum.min(*width),
+            _ => 0.0,
+        }
+    };
+    let max = match widths.get(index+1) {
+        Some(next) => widths[index] + next - minimum(index+1),
+        None => f32::INFINITY,
+    };
+    (index, value.min(max).max(minimum(index)))
 }
 
+/// Finds the handle under the cursor, the one with the nearest center
+/// when handles overlap.
 fn find_mouse_over_handle_bounds(
     handle_bounds: &[Rectangle],
     cursor: mouse::Cursor) 
     -> Option<usize> {
-        for (index, bounds) in handle_bounds.iter().enumerate() {
-            if cursor.is_over(*bounds) {
-                return Some(index)
-            }
+        let position = cursor.position()?;
+
+        handle_bounds.iter()
+            .enumerate()
+            .filter(|(_, bounds)| cursor.is_over(**bounds))
+            .min_by(|(_, a), (_, b)| {
+                a.center().distance(position).total_cmp(&b.center().distance(position))
+            })
+            .map(|(index, _)| index)
+}
+
+/// Picks, among the handles on top of the pressed one, the one that can move
+/// towards the position. The handles of a collapsed container overlap and
+/// only the one after it can move right or down.
+fn movable_handle(
+    handle_bounds: &[Rectangle],
+    index: usize,
+    position: Point,
+    direction: Direction,
+) -> usize {
+    let axis = |point: Point| match direction {
+        Direction::Horizontal => point.x,
+        Direction::Vertical => point.y,
+    };
+    let Some(pressed) = handle_bounds.get(index) else {
+        return index;
+    };
+    let center = axis(pressed.center());
+    let overlaps = |i: usize| {
+        handle_bounds.get(i).is_some_and(|bounds| (axis(bounds.center()) - center).abs() < 0.5)
+    };
+
+    let mut index = index;
+    if axis(position) > center {
+        while overlaps(index+1) {
+            index += 1;
         }
-        None
+    } else {
+        while index > 0 && overlaps(index-1) {
+            index -= 1;
+        }
+    }
+    index
 }
 
 /// The direction of [`Scrollable`].
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Direction {
     /// Horizontal resizing
     #[default]
@@ -664,14 +3574,192 @@ pub enum Direction {
     Vertical,
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
-struct State {
+/// Where a handle of a [`Divider`] sits on the edge of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    /// The handle starts at the edge
+    Start,
+    /// The handle is centered on the edge
+    Center,
+    /// The handle ends at the edge
+    End,
+}
+
+/// Where the tick marks of a [`Divider`] are drawn, see [`Divider::ticks`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TickMarks {
+    /// At the allowed values of [`Divider::steps`].
+    Steps,
+    /// Every given distance from the start of the container.
+    Every(f32),
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct State {
     is_dragging: bool,
     index: usize,
-    handle_bounds: Vec<Rectangle>,
+    hovered: Option<usize>,
+    menu: Option<(usize, Point)>,
+    last_click: Option<mouse::Click>,
+    last_tap: Option<(Point, Instant)>,
+    long_press: Option<(usize, Point, Instant)>,
+    focused: Option<usize>,
+    is_focus_visible: bool,
+    status: Option<Status>,
+    transition: Option<(Status, Instant)>,
+    now: Option<Instant>,
+    last_interaction: Option<Instant>,
+    is_out_of_range: bool,
+    #[cfg(debug_assertions)]
+    has_warned: bool,
+    limit: Option<Limit>,
+    snapped: Option<f32>,
+    stepped: Option<f32>,
+    published: Option<f32>,
+    held_back: Option<(usize, f32)>,
+    ghost: Option<(usize, f32)>,
+    modifiers: keyboard::Modifiers,
+    session: Option<mpsc::UnboundedSender<DividerEvent>>,
+    handles_overlaid: bool,
+    drag_start: Vec<f32>,
+    press_origin: Option<Point>,
+    drag_position: Option<Point>,
+    drag_viewport: Option<Rectangle>,
+    pub(crate) hidden: BTreeMap<usize, f32>,
+    pub(crate) default_widths: Option<Vec<f32>>,
+    pub(crate) weighted_widths: Option<Vec<f32>>,
+    minimums: Vec<f32>,
+    count: usize,
+    total: f32,
+    viewport: Option<Rectangle>,
+    extent: Option<f32>,
+    resized: Option<Vec<f32>>,
+    pub(crate) handle_bounds: Vec<Rectangle>,
+    hit_bounds: Vec<Rectangle>,
     width_height_bounds: Vec<Rectangle>,
 }
 
+impl State {
+    /// Forgets the drag.
+    fn end_drag(&mut self) {
+        self.is_dragging = false;
+        self.index = 0;
+        self.limit = None;
+        self.snapped = None;
+        self.stepped = None;
+        self.published = None;
+        self.held_back = None;
+        self.ghost = None;
+        self.drag_start = vec![];
+        self.press_origin = None;
+        self.drag_position = None;
+        self.drag_viewport = None;
+    }
+
+    /// Takes the change published at the end of the drag, the last one
+    /// held back by min_delta or else the one of a ghost drag.
+    fn take_released(&mut self) -> Option<(usize, f32)> {
+        let ghost = self.ghost.take();
+        self.held_back.take().or(ghost)
+    }
+
+    /// The progress of the current drag.
+    pub(crate) fn drag_progress(&self) -> Option<DragProgress> {
+        if !self.is_dragging {
+            return None;
+        }
+        let (origin, position) = (self.press_origin?, self.drag_position?);
+
+        Some(DragProgress {
+            index: self.index,
+            origin,
+            position,
+            offset: position - origin,
+            start: self.drag_start.get(self.index).copied().unwrap_or_default(),
+            value: self.published,
+        })
+    }
+}
+
+/// The progress of a drag of a [`Divider`] handle, see
+/// [`operation::drag_progress`](crate::operation::drag_progress).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragProgress {
+    /// The index of the dragged handle.
+    pub index: usize,
+    /// Where the handle was pressed.
+    pub origin: Point,
+    /// Where the cursor is.
+    pub position: Point,
+    /// How far the cursor moved from where the handle was pressed.
+    pub offset: Vector,
+    /// The width or height of the container when the drag started, in pixels.
+    pub start: f32,
+    /// The last value published by the drag, in pixels.
+    pub value: Option<f32>,
+}
+
+/// A change sent by a [`Divider`] to its channel, see [`Divider::sender`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DividerChange {
+    /// The id of the divider.
+    pub id: Option<Id>,
+    /// The index of the handle.
+    pub index: usize,
+    /// The width or height of the container.
+    pub value: f32,
+}
+
+/// A drag of a [`Divider`] handle, sent to the channel of
+/// [`Divider::drag_sessions`] when it starts.
+#[derive(Debug)]
+pub struct DragSession {
+    /// The id of the divider.
+    pub id: Option<Id>,
+    /// The index of the dragged handle.
+    pub index: usize,
+    /// The events of the drag, from the start to the end or the cancelation.
+    pub events: mpsc::UnboundedReceiver<DividerEvent>,
+}
+
+/// The phase of a drag of a [`Divider`] handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragPhase {
+    /// The handle was pressed.
+    Started,
+    /// The handle was moved.
+    Moved,
+    /// The handle was released.
+    Ended,
+    /// The drag was interrupted, by a lost finger, a long press or the
+    /// divider being disabled. The value is the one before the drag.
+    Canceled,
+}
+
+/// A drag of a [`Divider`] handle, see [`Divider::on_divider_event`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DividerEvent {
+    /// The index of the handle.
+    pub index: usize,
+    /// The width or height of the container.
+    pub value: f32,
+    /// The value before the event, or before the drag when it ended.
+    pub previous: f32,
+    /// The phase of the drag.
+    pub phase: DragPhase,
+    /// The keyboard modifiers pressed.
+    pub modifiers: keyboard::Modifiers,
+}
+
+/// The limit a handle of a [`Divider`] is pinned at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// The container before the handle has no size left.
+    Start,
+    /// The container after the handle has no size left or the total reached its maximum.
+    End,
+}
+
 /// The possible status of a [`Divider`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -681,6 +3769,8 @@ pub enum Status {
     Hovered,
     /// The [`Divider`] is being dragged.
     Dragged,
+    /// The [`Divider`] is disabled.
+    Disabled,
 }
 
 /// The appearance of a Divider.
@@ -694,6 +3784,80 @@ pub struct Style {
     pub border_color: Color,
     /// The border [`Radius`] of the handle.
     pub border_radius: Radius,
+    /// The thickness of the handle drawn while it is hovered or dragged.
+    /// The area reacting to the cursor keeps its size.
+    pub hovered_thickness: Option<f32>,
+    /// The ring drawn around the handle focused with the keyboard.
+    pub focus_ring: Option<FocusRing>,
+    /// The [`Color`] of the guide line drawn while dragging, see [`Divider::guides`].
+    pub guide_color: Color,
+    /// The tick marks drawn along the track, see [`Divider::ticks`].
+    pub ticks: Option<TickStyle>,
+    /// The [`Color`] of the labels of the marks, see [`Divider::marks`].
+    pub mark_color: Color,
+}
+
+/// The appearance of the tick marks of a Divider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickStyle {
+    /// The [`Color`] of the marks.
+    pub color: Color,
+    /// The length of the marks across the track.
+    pub length: f32,
+    /// The width of the marks.
+    pub width: f32,
+}
+
+/// The ring around the focused handle of a Divider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusRing {
+    /// The [`Color`] of the ring.
+    pub color: Color,
+    /// The width of the ring.
+    pub width: f32,
+    /// The space between the handle and the ring.
+    pub offset: f32,
+}
+
+/// The appearance of the context menu of a Divider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MenuStyle {
+    /// The [`Background`] of the menu.
+    pub background: Background,
+    /// The [`Border`] of the menu.
+    pub border: Border,
+    /// The text [`Color`] of the entries.
+    pub text_color: Color,
+    /// The [`Background`] of the entry under the cursor.
+    pub selected_background: Background,
+    /// The text [`Color`] of the entry under the cursor.
+    pub selected_text_color: Color,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        MenuStyle {
+            background: Color::WHITE.into(),
+            border: Border {
+                width: 1.0,
+                radius: 0.0.into(),
+                color: Color::from_rgb(0.7, 0.7, 0.7),
+            },
+            text_color: Color::BLACK,
+            selected_background: Color::from_rgb(0.36, 0.49, 0.92).into(),
+            selected_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// What a [`Divider`] is used for, to give each use its own default
+/// class application-wide, see [`Catalog::role`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// The columns of a table.
+    Column,
+    /// The panels of a layout.
+    Panel,
 }
 
 /// The theme catalog of a [`Divider`].
@@ -704,8 +3868,21 @@ pub trait Catalog: Sized {
     /// The default class produced by the [`Catalog`].
     fn default<'a>() -> Self::Class<'a>;
 
+    /// The default class of the dividers playing the given [`Role`],
+    /// the default class unless the theme tells them apart.
+    fn role<'a>(role: Role) -> Self::Class<'a> {
+        let _ = role;
+        Self::default()
+    }
+
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`MenuStyle`] of the context menu, a light menu unless
+    /// the theme styles it.
+    fn menu_style(&self) -> MenuStyle {
+        MenuStyle::default()
+    }
 }
 
 /// A styling function for a [`Divider`].
@@ -718,9 +3895,32 @@ impl Catalog for Theme {
         Box::new(primary)
     }
 
+    fn role<'a>(role: Role) -> Self::Class<'a> {
+        match role {
+            Role::Column => Box::new(neutral),
+            Role::Panel => Box::new(primary),
+        }
+    }
+
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn menu_style(&self) -> MenuStyle {
+        let palette = self.extended_palette();
+
+        MenuStyle {
+            background: palette.background.base.color.into(),
+            border: Border {
+                width: 1.0,
+                radius: 0.0.into(),
+                color: palette.background.strong.color,
+            },
+            text_color: palette.background.base.text,
+            selected_background: palette.primary.strong.color.into(),
+            selected_text_color: palette.primary.strong.text,
+        }
+    }
 }
 
 /// The default style of a [`Divider`].
@@ -731,13 +3931,185 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
         Status::Active => palette.primary.strong.color,
         Status::Hovered => palette.primary.base.color,
         Status::Dragged => palette.primary.strong.color,
+        Status::Disabled => palette.background.strong.color,
     };
 
     Style {
         background: color.into(),
         border_color: Color::TRANSPARENT,
         border_width: 0.0,
-        border_radius: 0.0.into()
+        border_radius: 0.0.into(),
+        hovered_thickness: None,
+        focus_ring: Some(FocusRing {
+            color: palette.primary.base.color,
+            width: 2.0,
+            offset: 1.0,
+        }),
+        guide_color: palette.primary.weak.color,
+        ticks: Some(TickStyle {
+            color: palette.background.strong.color,
+            length: 4.0,
+            width: 1.0,
+        }),
+        mark_color: palette.background.base.text,
+    }
+}
+
+/// A neutral style of a [`Divider`] using the background colors
+/// of the palette instead of the accent colors.
+pub fn neutral(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let color = match status {
+        Status::Active => palette.background.weak.color,
+        Status::Hovered => palette.background.strong.color,
+        Status::Dragged => palette.background.strong.color,
+        Status::Disabled => palette.background.weak.color,
+    };
+
+    Style {
+        background: color.into(),
+        ..primary(theme, status)
+    }
+}
+
+/// A style of a [`Divider`] adapting its contrast to the theme,
+/// stronger on light themes and softer on dark themes.
+pub fn adaptive(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let is_dark = is_dark(palette.background.base.color);
+
+    let color = match (is_dark, status) {
+        (_, Status::Disabled) => palette.background.weak.color,
+        (true, Status::Active) => palette.background.weak.color,
+        (true, Status::Hovered | Status::Dragged) => palette.background.strong.color,
+        (false, Status::Active) => palette.background.strong.color,
+        (false, Status::Hovered | Status::Dragged) => palette.background.base.text,
+    };
+
+    Style {
+        background: color.into(),
+        ..primary(theme, status)
+    }
+}
+
+fn is_dark(color: Color) -> bool {
+    0.299 * color.r + 0.587 * color.g + 0.114 * color.b < 0.5
+}
+
+const FADE_DURATION: Duration = Duration::from_millis(300);
+const DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(300);
+const DOUBLE_TAP_DISTANCE: f32 = 10.0;
+const GUIDE_WIDTH: f32 = 1.0;
+const MARK_TEXT_SCALE: f32 = 0.75;
+const STEP_LABEL_DISTANCE: f32 = 24.0;
+const HIT_BIAS: f32 = 3.0;
+const TRACK_WIDTH: f32 = 1.0;
+const AUTO_SCROLL_EDGE: f32 = 32.0;
+const AUTO_SCROLL_SPEED: f32 = 12.0;
+const SNAP_HYSTERESIS: f32 = 4.0;
+
+/// The offset to scroll by in a frame when the position is near an edge of
+/// the viewport, faster closer to the edge and at full speed beyond it.
+fn auto_scroll_offset(direction: Direction, position: Point, viewport: Rectangle) -> f32 {
+    let (position, start, end) = match direction {
+        Direction::Horizontal => (position.x, viewport.x, viewport.x + viewport.width),
+        Direction::Vertical => (position.y, viewport.y, viewport.y + viewport.height),
+    };
+    let edge = AUTO_SCROLL_EDGE.min((end - start) / 2.0);
+    if edge <= 0.0 {
+        return 0.0;
+    }
+    let speed = |distance: f32| AUTO_SCROLL_SPEED * (1.0 - distance / edge).clamp(0.0, 1.0);
+
+    if position > end - edge {
+        speed(end - position)
+    } else if position < start + edge {
+        -speed(position - start)
+    } else {
+        0.0
+    }
+}
+
+fn event_time(event: &Event) -> Instant {
+    match event {
+        Event::Window(window::Event::RedrawRequested(now)) => *now,
+        _ => Instant::now(),
+    }
+}
+
+fn fade_style(style: Style, opacity: f32) -> Style {
+    if opacity >= 1.0 {
+        return style;
+    }
+    let background = match style.background {
+        Background::Color(color) => Background::Color(Color {
+            a: color.a * opacity,
+            ..color
+        }),
+        background => background,
+    };
+
+    Style {
+        background,
+        border_color: Color {
+            a: style.border_color.a * opacity,
+            ..style.border_color
+        },
+        focus_ring: style.focus_ring.map(|ring| FocusRing {
+            color: Color {
+                a: ring.color.a * opacity,
+                ..ring.color
+            },
+            ..ring
+        }),
+        guide_color: Color {
+            a: style.guide_color.a * opacity,
+            ..style.guide_color
+        },
+        ticks: style.ticks.map(|tick| TickStyle {
+            color: Color {
+                a: tick.color.a * opacity,
+                ..tick.color
+            },
+            ..tick
+        }),
+        mark_color: Color {
+            a: style.mark_color.a * opacity,
+            ..style.mark_color
+        },
+        ..style
+    }
+}
+
+fn mix_style(from: Style, to: Style, progress: f32) -> Style {
+    let background = match (from.background, to.background) {
+        (Background::Color(from), Background::Color(to)) => {
+            Background::Color(mix_color(from, to, progress))
+        },
+        // gradients are switched half way
+        (from, to) => if progress < 0.5 { from } else { to },
+    };
+
+    Style {
+        background,
+        border_width: from.border_width + (to.border_width - from.border_width) * progress,
+        border_color: mix_color(from.border_color, to.border_color, progress),
+        border_radius: to.border_radius,
+        hovered_thickness: to.hovered_thickness,
+        focus_ring: to.focus_ring,
+        guide_color: to.guide_color,
+        ticks: to.ticks,
+        mark_color: mix_color(from.mark_color, to.mark_color, progress),
+    }
+}
+
+fn mix_color(from: Color, to: Color, progress: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * progress,
+        g: from.g + (to.g - from.g) * progress,
+        b: from.b + (to.b - from.b) * progress,
+        a: from.a + (to.a - from.a) * progress,
     }
 }
 
@@ -778,6 +4150,7 @@ fn test_get_handle_bounds() {
         get_handle_bounds(
             hz_bounds, 
             &widths_heights, 
+            &[],
             hz_handle_width, 
             hz_handle_height,
             &hz_handle_offsets,
@@ -788,6 +4161,7 @@ fn test_get_handle_bounds() {
         get_handle_bounds(
             vt_bounds, 
             &widths_heights, 
+            &[],
             vt_handle_width, 
             vt_handle_height,
             &vt_handle_offsets,
@@ -829,6 +4203,25 @@ fn test_find_mouse_over_handle_bounds() {
 
 }
 
+#[test]
+fn test_gaps() {
+    let bounds = Rectangle{x: 0.0, y: 0.0, width: 330.0, height: 21.0};
+    let widths = [100.0, 100.0, 100.0];
+    let gaps = [10.0, 20.0];
+
+    let handle_bounds =
+        get_handle_bounds(bounds, &widths, &gaps, 4.0, 21.0, &[-2.0, -2.0, -4.0], true, Direction::Horizontal);
+    let w_h_bounds =
+        get_width_height_bounds(bounds, &widths, &gaps, 4.0, 21.0, Direction::Horizontal);
+
+    // the handles are centered in the gaps, the last one ends with the containers
+    let x: Vec<f32> = handle_bounds.iter().map(|bounds| bounds.x).collect();
+    assert_eq!(x, vec![103.0, 218.0, 326.0]);
+
+    let x: Vec<f32> = w_h_bounds.iter().map(|bounds| bounds.x).collect();
+    assert_eq!(x, vec![0.0, 110.0, 230.0]);
+}
+
 #[test] 
 fn test_get_width_height_bounds() {
     let widths_heights = vec![100.0, 100.0, 100.0, 100.0];
@@ -852,6 +4245,7 @@ fn test_get_width_height_bounds() {
         get_width_height_bounds(
             hz_bounds, 
             &widths_heights, 
+            &[],
             hz_handle_width, 
             hz_handle_height, 
             hz_direction);
@@ -860,6 +4254,7 @@ fn test_get_width_height_bounds() {
         get_width_height_bounds(
             vt_bounds, 
             &widths_heights, 
+            &[],
             vt_handle_width, 
             vt_handle_height, 
             vt_direction);
@@ -879,4 +4274,300 @@ fn test_get_width_height_bounds() {
     assert_eq!(hz_results, hz_bounds);
     assert_eq!(vt_results, vt_bounds);
 
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_snap_collapsed() {
+    let widths = [100.0, 200.0, 150.0];
+
+    assert_eq!(snap_collapsed(&widths, 20.0, (0, 15.0)), (0, 0.0));
+    assert_eq!(snap_collapsed(&widths, 20.0, (0, 50.0)), (0, 50.0));
+    assert_eq!(snap_collapsed(&widths, 20.0, (0, 290.0)), (0, 300.0));
+    assert_eq!(snap_collapsed(&widths, 20.0, (2, 400.0)), (2, 400.0));
+}
+
+#[test]
+fn test_scroll_bounds() {
+    let bounds = Rectangle{x: 0.0, y: 0.0, width: 400.0, height: 21.0};
+    let widths = vec![100.0, 100.0, 100.0];
+    let mut handle_bounds = 
+        get_handle_bounds(bounds, &widths, &[], 4.0, 21.0, &[-2.0; 3], true, Direction::Horizontal);
+    let mut w_h_bounds = 
+        get_width_height_bounds(bounds, &widths, &[], 4.0, 21.0, Direction::Horizontal);
+
+    scroll_bounds(&mut handle_bounds, &mut w_h_bounds, 1, 100.0, Direction::Horizontal);
+
+    // the frozen container stays, the others move
+    assert_eq!(w_h_bounds[0].x, 0.0);
+    assert_eq!(w_h_bounds[1].x, 0.0);
+    assert_eq!(handle_bounds[0].x, 98.0);
+    // scrolled under the frozen container
+    assert_eq!(handle_bounds[1].width, 0.0);
+    assert_eq!(handle_bounds[2].x, 198.0);
+    assert_eq!(handle_bounds[2].width, 4.0);
+}
+
+#[test]
+fn test_next_focus() {
+    use keyboard::key::Named;
+    use keyboard::Modifiers;
+
+    assert_eq!(next_focus(0, Named::Tab, Modifiers::empty(), 3, Direction::Horizontal), Some(1));
+    // the focus leaves after the last handle
+    assert_eq!(next_focus(2, Named::Tab, Modifiers::empty(), 3, Direction::Horizontal), None);
+    assert_eq!(next_focus(2, Named::Tab, Modifiers::SHIFT, 3, Direction::Horizontal), Some(1));
+    // arrows stay on the last handle
+    assert_eq!(next_focus(2, Named::ArrowRight, Modifiers::CTRL, 3, Direction::Horizontal), Some(2));
+    assert_eq!(next_focus(1, Named::ArrowUp, Modifiers::CTRL, 3, Direction::Vertical), Some(0));
+    // plain arrows move the handle instead
+    assert_eq!(next_focus(1, Named::ArrowRight, Modifiers::empty(), 3, Direction::Horizontal), None);
+}
+
+#[test]
+fn test_handle_rects() {
+    let bounds = Rectangle{x: 10.0, y: 0.0, width: 400.0, height: 21.0};
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> = 
+        divider_horizontal(vec![100.0, 200.0], 4.0, 21.0, |_| ());
+
+    let rects = divider.handle_rects(bounds);
+
+    assert_eq!(rects.len(), 2);
+    assert_eq!(rects[0], Rectangle{x: 108.0, y: 0.0, width: 4.0, height: 21.0});
+    // the last handle is pulled in to stay in bounds
+    assert_eq!(divider.handle_rect(1, bounds), Some(Rectangle{x: 306.0, y: 0.0, width: 4.0, height: 21.0}));
+    assert_eq!(divider.handle_rect(2, bounds), None);
+}
+
+#[test]
+fn test_handle_anchor() {
+    let bounds = Rectangle{x: 10.0, y: 0.0, width: 400.0, height: 21.0};
+
+    for anchor in [Anchor::Start, Anchor::Center, Anchor::End] {
+        let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+            divider_horizontal(vec![100.0, 200.0], 4.0, 21.0, |_| ())
+                .handle_anchor(anchor);
+
+        let rects = divider.handle_rects(bounds);
+        let x = match anchor {
+            Anchor::Start => 110.0,
+            Anchor::Center => 108.0,
+            Anchor::End => 106.0,
+        };
+        assert_eq!(rects[0].x, x);
+        // the last handle stays in bounds whatever the anchor
+        assert_eq!(rects[1].x + rects[1].width, bounds.x + 300.0);
+    }
+}
+
+#[test]
+fn test_keyed() {
+    let on_change = keyed(vec!["name", "size"], |change| change);
+
+    assert_eq!(on_change((1, 80.0)), ("size", 80.0));
+}
+
+#[test]
+fn test_tick_values() {
+    assert_eq!(tick_values(&TickMarks::Every(50.0), &[], 160.0), vec![50.0, 100.0, 150.0]);
+    assert_eq!(tick_values(&TickMarks::Steps, &[100.0, 300.0], 200.0), vec![100.0]);
+    assert!(tick_values(&TickMarks::Every(0.0), &[], 160.0).is_empty());
+}
+
+#[test]
+fn test_movable_handle() {
+    // the second container is collapsed
+    let handle_bounds = vec![
+        Rectangle { x: 98.0, y: 0.0, width: 4.0, height: 21.0 },
+        Rectangle { x: 98.0, y: 0.0, width: 4.0, height: 21.0 },
+        Rectangle { x: 298.0, y: 0.0, width: 4.0, height: 21.0 }];
+
+    let right = Point::new(120.0, 10.0);
+    let left = Point::new(80.0, 10.0);
+
+    assert_eq!(movable_handle(&handle_bounds, 0, right, Direction::Horizontal), 1);
+    assert_eq!(movable_handle(&handle_bounds, 1, left, Direction::Horizontal), 0);
+    assert_eq!(movable_handle(&handle_bounds, 2, left, Direction::Horizontal), 2);
+}
+
+#[test]
+fn test_keep_order() {
+    let widths = [100.0, 50.0, 200.0];
+
+    assert_eq!(keep_order(&widths, (0, 180.0)), (0, 150.0));
+    assert_eq!(keep_order(&widths, (1, -20.0)), (1, 0.0));
+    // the last handle only changes the total
+    assert_eq!(keep_order(&widths, (2, 300.0)), (2, 300.0));
+}
+
+#[test]
+fn test_keep_minimums() {
+    let minimums = [80.0, 60.0, 500.0];
+    let widths = [100.0, 100.0, 200.0];
+
+    assert_eq!(keep_minimums(&minimums, &widths, (0, 50.0)), (0, 80.0));
+    assert_eq!(keep_minimums(&minimums, &widths, (0, 190.0)), (0, 140.0));
+    // the last container is already smaller than its content and can't shrink
+    assert_eq!(keep_minimums(&minimums, &widths, (1, 150.0)), (1, 100.0));
+    assert_eq!(keep_minimums(&minimums, &widths, (1, 70.0)), (1, 70.0));
+}
+
+#[test]
+fn test_bias_hit_bounds() {
+    let handle_bounds = vec![
+        Rectangle { x: -2.0, y: 0.0, width: 4.0, height: 21.0 },
+        Rectangle { x: 98.0, y: 0.0, width: 4.0, height: 21.0 }];
+
+    // the first container is collapsed, its handle can only move right
+    let hit_bounds = bias_hit_bounds(&handle_bounds, &[0.0, 100.0], Direction::Horizontal);
+
+    assert_eq!(hit_bounds[0], Rectangle { x: -2.0, y: 0.0, width: 7.0, height: 21.0 });
+    assert_eq!(hit_bounds[1], handle_bounds[1]);
+}
+
+#[test]
+fn test_auto_scroll_offset() {
+    let viewport = Rectangle::new(Point::new(100.0, 0.0), Size::new(400.0, 300.0));
+    let offset = |x: f32| auto_scroll_offset(Direction::Horizontal, Point::new(x, 10.0), viewport);
+
+    assert_eq!(offset(300.0), 0.0);
+    assert_eq!(offset(484.0), AUTO_SCROLL_SPEED / 2.0);
+    assert_eq!(offset(600.0), AUTO_SCROLL_SPEED);
+    assert_eq!(offset(100.0), -AUTO_SCROLL_SPEED);
+    // the vertical position is out of the viewport but not near an edge
+    assert_eq!(auto_scroll_offset(Direction::Vertical, Point::new(0.0, 150.0), viewport), 0.0);
+}
+
+#[test]
+fn test_mirrored() {
+    // side panels around a centered pane
+    let mut widths = vec![200.0, 400.0, 200.0];
+
+    let mirror = mirrored(&widths, (0, 250.0)).unwrap();
+    assert_eq!(mirror, (1, 300.0));
+    resize::apply(&mut widths, (0, 250.0));
+    resize::apply(&mut widths, mirror);
+    assert_eq!(widths, vec![250.0, 300.0, 250.0]);
+
+    // the middle handle of four panes and the last handle have no pair
+    assert_eq!(mirrored(&[100.0; 4], (1, 120.0)), None);
+    assert_eq!(mirrored(&[100.0; 4], (3, 120.0)), None);
+    assert_eq!(mirrored(&[100.0; 4], (2, 80.0)), Some((0, 120.0)));
+}
+
+#[test]
+fn test_update_extent() {
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+        divider_horizontal(vec![200.0, 600.0], 4.0, 21.0, |_| ())
+            .resize_strategy(resize::Strategy::Start)
+            .on_changes(|_| ());
+    let mut state = State::default();
+
+    // the first layout only records the size
+    divider.update_extent(&mut state, Size::new(800.0, 21.0));
+    assert_eq!(state.resized, None);
+
+    divider.update_extent(&mut state, Size::new(1000.0, 21.0));
+    assert_eq!(state.resized, Some(vec![200.0, 800.0]));
+
+    // a second resize before the first is published starts from it
+    divider.update_extent(&mut state, Size::new(900.0, 21.0));
+    assert_eq!(state.resized, Some(vec![200.0, 700.0]));
+}
+
+#[test]
+fn test_apply_dragged() {
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+        divider_horizontal(vec![200.0, 400.0, 200.0], 4.0, 21.0, |_| ())
+            .mirror(true)
+            .on_changes(|_| ());
+
+    // a ghost drag shows the paired handle moved as well
+    let mut widths = vec![200.0, 400.0, 200.0];
+    divider.apply_dragged(&mut widths, (0, 250.0));
+    assert_eq!(widths, vec![250.0, 300.0, 250.0]);
+
+    // without on_changes the drag isn't mirrored
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+        divider_horizontal(vec![200.0, 400.0, 200.0], 4.0, 21.0, |_| ())
+            .mirror(true);
+
+    let mut widths = vec![200.0, 400.0, 200.0];
+    divider.apply_dragged(&mut widths, (0, 250.0));
+    assert_eq!(widths, vec![250.0, 350.0, 200.0]);
+}
+
+#[test]
+fn test_keep_snapped() {
+    let steps = |value: f32| if value < 150.0 { 100.0 } else { 200.0 };
+
+    // around the midpoint between the steps, the previous step is kept
+    assert_eq!(keep_snapped(152.0, 100.0, 4.0, steps), 100.0);
+    assert_eq!(keep_snapped(148.0, 200.0, 4.0, steps), 200.0);
+    assert_eq!(keep_snapped(155.0, 100.0, 4.0, steps), 200.0);
+    assert_eq!(keep_snapped(152.0, 100.0, 0.0, steps), 200.0);
+
+    // a collapsed container opens past the threshold and the hysteresis
+    let collapse = |value: f32| if value < 20.0 { 0.0 } else { value };
+    assert_eq!(keep_snapped(22.0, 0.0, 4.0, collapse), 0.0);
+    assert_eq!(keep_snapped(25.0, 0.0, 4.0, collapse), 25.0);
+    assert_eq!(keep_snapped(19.0, 30.0, 4.0, collapse), 0.0);
+}
+
+#[test]
+fn test_take_released() {
+    let mut state = State::default();
+
+    // the last change below min_delta comes after the ghost published last
+    state.ghost = Some((1, 100.0));
+    state.held_back = Some((1, 103.0));
+    assert_eq!(state.take_released(), Some((1, 103.0)));
+    assert_eq!(state.take_released(), None);
+
+    state.ghost = Some((1, 100.0));
+    assert_eq!(state.take_released(), Some((1, 100.0)));
+}
+
+#[test]
+fn test_clamp_total_with_gaps() {
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+        divider_horizontal(vec![100.0, 100.0, 100.0], 4.0, 21.0, |_| ())
+            .gaps(vec![10.0, 20.0])
+            .max_total(400.0);
+
+    // the gaps take 30.0 of the total
+    assert_eq!(divider.clamp_total((2, 200.0)), (2, 170.0));
+    assert_eq!(divider.clamp_total((1, 200.0)), (1, 200.0));
+}
+
+#[test]
+fn test_emit_ratios_with_gaps() {
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+        divider_horizontal(vec![100.0, 300.0], 4.0, 21.0, |_| ())
+            .gaps(vec![20.0])
+            .emit_ratios(true);
+
+    let ratios: Vec<f32> = [100.0, 300.0].into_iter()
+        .enumerate()
+        .map(|change| divider.to_published(change).1)
+        .collect();
+
+    assert_eq!(ratios, vec![0.25, 0.75]);
+    assert_eq!(resize::from_weights(&ratios, 400.0), vec![100.0, 300.0]);
+}
+
+#[test]
+fn test_units() {
+    // a timeline starting at 5 seconds, 10 pixels per second
+    let divider: Divider<'_, (), Theme, iced::Renderer, _> =
+        divider_horizontal(vec![2.0, 3.0], 4.0, 21.0, |_| ())
+            .steps(vec![1.0])
+            .max_total(10.0)
+            .units(|pixels| pixels / 10.0 + 5.0, |seconds| (seconds - 5.0) * 10.0)
+            .into_pixels();
+
+    assert_eq!(divider.widths, vec![20.0, 30.0]);
+    assert_eq!(divider.steps, vec![10.0]);
+    assert_eq!(divider.max_total, Some(100.0));
+    assert_eq!(divider.to_published((1, 40.0)), (1, 4.0));
+    assert_eq!(divider.to_published_widths(&[20.0, 30.0]), vec![2.0, 3.0]);
+}
@@ -1,3 +1,13 @@
 
 
+pub mod columns;
 pub mod divider;
+pub mod measure;
+pub mod operation;
+pub mod persistence;
+pub mod props;
+pub mod resize;
+pub mod split_scroll;
+pub mod table;
+pub mod table_header;
+pub mod tracks;
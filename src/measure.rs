@@ -0,0 +1,259 @@
+//! Measure the natural size of the content of a pane.
+//!
+//! Wrap the content of each pane in [`measured`] and query the sizes with
+//! [`operation::natural_sizes`](crate::operation::natural_sizes) to size
+//! the panes to their content or to find sensible minimums.
+use iced::advanced::layout;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::Operation;
+use iced::advanced::{Clipboard, Layout, Shell, Widget};
+use iced::event::{self, Event};
+use iced::{mouse, Element, Length, Rectangle, Size, Vector};
+
+/// Wraps the content to measure its natural size.
+pub fn measured<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Measured<'a, Message, Theme, Renderer> {
+    Measured::new(content)
+}
+
+/// Measures the size its content takes without limits, while laying it
+/// out within the limits as usual.
+///
+/// The content is only measured when its size is asked for, by an operation
+/// or by a [`Divider`](crate::divider::Divider) with content minimums, so the
+/// layout costs nothing more otherwise. Content filling the available space
+/// has no natural size and is measured at the size it is laid out with.
+#[allow(missing_debug_implementations)]
+pub struct Measured<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Measured<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Measured`].
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Measured {
+            content: content.into(),
+        }
+    }
+}
+
+/// Collects the natural sizes of the [`Measured`] widgets in the tree,
+/// in order, without looking inside them.
+pub(crate) fn natural_sizes(tree: &Tree) -> Vec<Size> {
+    let mut sizes = vec![];
+    collect_natural_sizes(tree, &mut sizes);
+    sizes
+}
+
+fn collect_natural_sizes(tree: &Tree, sizes: &mut Vec<Size>) {
+    if tree.tag == tree::Tag::of::<State>() {
+        sizes.push(tree.state.downcast_ref::<State>().natural);
+        return;
+    }
+    for child in &tree.children {
+        collect_natural_sizes(child, sizes);
+    }
+}
+
+/// Asks the [`Measured`] widgets in the tree to measure their content
+/// the next time they are laid out.
+pub(crate) fn request_natural_sizes(tree: &mut Tree) {
+    if tree.tag == tree::Tag::of::<State>() {
+        tree.state.downcast_mut::<State>().is_requested = true;
+        return;
+    }
+    for child in &mut tree.children {
+        request_natural_sizes(child);
+    }
+}
+
+/// The state of a [`Measured`] widget, found by the operations.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct State {
+    pub(crate) natural: Size,
+    is_requested: bool,
+}
+
+/// The natural size, or the laid out size along an axis the content fills.
+fn natural_or(natural: Size, size: Size) -> Size {
+    Size::new(
+        if natural.width.is_finite() { natural.width } else { size.width },
+        if natural.height.is_finite() { natural.height } else { size.height },
+    )
+}
+
+impl<Message, Theme, Renderer> Measured<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Lays the content out without limits.
+    fn measure(&self, tree: &mut Tree, renderer: &Renderer) -> Size {
+        let unlimited = layout::Limits::new(Size::ZERO, Size::INFINITY);
+
+        self.content.as_widget()
+            .layout(tree, renderer, &unlimited)
+            .size()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Measured<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        // measured first, the content keeps the state of the layout below
+        let natural = std::mem::take(&mut state.is_requested)
+            .then(|| self.measure(&mut tree.children[0], renderer));
+
+        let node = self.content.as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let size = node.size();
+        if let Some(natural) = natural {
+            tree.state.downcast_mut::<State>().natural = natural_or(natural, size);
+        }
+
+        layout::Node::with_children(size, vec![node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // measured apart, the state of the content is left as laid out
+        let natural = self.measure(&mut Tree::new(&self.content), renderer);
+        let state = tree.state.downcast_mut::<State>();
+        state.natural = natural_or(natural, layout.bounds().size());
+
+        operation.custom(state, None);
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Measured<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(measured: Measured<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(measured)
+    }
+}
@@ -0,0 +1,179 @@
+//! Query the state of a [`Divider`](crate::divider::Divider) by its [`Id`].
+//!
+//! Run an operation with `iced::advanced::widget::operate`, which returns a
+//! task producing its result.
+use std::any::Any;
+
+use iced::advanced::widget::operation::{Operation, Outcome};
+use iced::advanced::widget::Id;
+use iced::{Rectangle, Size};
+
+use crate::divider::{DragProgress, State};
+use crate::measure;
+
+/// Produces the bounds of all the handles of the divider with the given [`Id`],
+/// so overlays can be positioned next to a given handle.
+pub fn handle_bounds(id: Id) -> impl Operation<Vec<Rectangle>> {
+    Query::new(id, |state: &mut State| state.handle_bounds.clone())
+}
+
+/// Produces the size a container of the divider with the given [`Id`] had
+/// before a drag collapsed it, see [`Divider::on_hidden`](crate::divider::Divider::on_hidden),
+/// and forgets it. Apply it like any change of the divider to bring the container back.
+pub fn restore(id: Id, index: usize) -> impl Operation<Option<(usize, f32)>> {
+    Query::new(id, move |state: &mut State| {
+        state.hidden.remove(&index).map(|width| (index, width))
+    })
+}
+
+/// Produces the progress of the drag of the divider with the given [`Id`],
+/// or `None` when no handle is dragged, to drive animations along with it.
+pub fn drag_progress(id: Id) -> impl Operation<Option<DragProgress>> {
+    Query::new(id, |state: &mut State| state.drag_progress())
+}
+
+/// Produces the natural sizes of the [`measured`](crate::measure::measured) panes
+/// in the content of the divider with the given [`Id`], in the order of the panes.
+pub fn natural_sizes(id: Id) -> impl Operation<Vec<Size>> {
+    NaturalSizes {
+        target: id,
+        is_inside: false,
+        sizes: vec![],
+    }
+}
+
+/// Produces the widths or heights of the divider with the given [`Id`] from
+/// its weights, see [`Divider::weights`](crate::divider::Divider::weights),
+/// scaled to its current total. Replace the widths with them to restore the layout.
+pub fn reset_to_weights(id: Id) -> impl Operation<Option<Vec<f32>>> {
+    Query::new(id, |state: &mut State| state.weighted_widths.clone())
+}
+
+/// Produces the default widths or heights of every divider with some, see
+/// [`Divider::default_widths`](crate::divider::Divider::default_widths), with
+/// the [`Id`] of the divider. Apply them to reset the whole layout at once.
+pub fn reset_all() -> impl Operation<Vec<(Option<Id>, Vec<f32>)>> {
+    ResetAll { defaults: vec![] }
+}
+
+/// Finds the state of the divider with the target [`Id`] and
+/// produces the result of the query on it.
+struct Query<T, F> {
+    target: Id,
+    query: F,
+    output: Option<T>,
+}
+
+impl<T, F> Query<T, F>
+where
+    F: Fn(&mut State) -> T,
+{
+    fn new(target: Id, query: F) -> Self {
+        Self {
+            target,
+            query,
+            output: None,
+        }
+    }
+}
+
+impl<T, F> Operation<T> for Query<T, F>
+where
+    T: Clone + Send,
+    F: Fn(&mut State) -> T + Send,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.target) {
+            return;
+        }
+        if let Some(state) = state.downcast_mut::<State>() {
+            self.output = Some((self.query)(state));
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match &self.output {
+            Some(output) => Outcome::Some(output.clone()),
+            None => Outcome::None,
+        }
+    }
+}
+
+/// Collects the sizes of the measured panes inside the target divider.
+struct NaturalSizes {
+    target: Id,
+    is_inside: bool,
+    sizes: Vec<Size>,
+}
+
+impl Operation<Vec<Size>> for NaturalSizes {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<Size>>),
+    ) {
+        let is_target = id == Some(&self.target);
+        if is_target {
+            self.is_inside = true;
+            self.sizes.clear();
+        }
+
+        operate_on_children(self);
+
+        if is_target {
+            self.is_inside = false;
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        if !self.is_inside {
+            return;
+        }
+        if let Some(state) = state.downcast_ref::<measure::State>() {
+            self.sizes.push(state.natural);
+        }
+    }
+
+    fn finish(&self) -> Outcome<Vec<Size>> {
+        Outcome::Some(self.sizes.clone())
+    }
+}
+
+/// Collects the default widths of every divider.
+struct ResetAll {
+    defaults: Vec<(Option<Id>, Vec<f32>)>,
+}
+
+impl Operation<Vec<(Option<Id>, Vec<f32>)>> for ResetAll {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<(Option<Id>, Vec<f32>)>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        let Some(state) = state.downcast_ref::<State>() else {
+            return;
+        };
+        if let Some(default_widths) = &state.default_widths {
+            self.defaults.push((id.cloned(), default_widths.clone()));
+        }
+    }
+
+    fn finish(&self) -> Outcome<Vec<(Option<Id>, Vec<f32>)>> {
+        Outcome::Some(self.defaults.clone())
+    }
+}
@@ -0,0 +1,224 @@
+//! Save and restore the pane sizes of a [`Divider`](crate::divider::Divider).
+//!
+//! With the `serde` feature a [`DividerLayout`] can be embedded in any settings
+//! struct of the application. The `toml` feature adds helpers to read and write
+//! it directly as a TOML document. There are no JSON helpers, a layout is
+//! written as JSON with `serde_json` like any other serde type. A
+//! [`LayoutSnapshot`] holds the layouts of all the dividers of a window, to
+//! switch between saved workspaces.
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The persisted widths or heights of the containers controlled by a divider.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DividerLayout {
+    /// The layout version of the application that saved it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub version: u32,
+    /// The widths (horizontal) or heights (vertical) of the containers.
+    pub widths: Vec<f32>,
+    /// Whether each container is collapsed, empty when none is, see
+    /// [`Tracks::layout`](crate::tracks::Tracks::layout).
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub collapsed: Vec<bool>,
+}
+
+impl DividerLayout {
+    /// Creates a new [`DividerLayout`] from the current widths or heights.
+    pub fn new(widths: Vec<f32>) -> Self {
+        Self {
+            version: 0,
+            widths,
+            collapsed: vec![],
+        }
+    }
+
+    /// Sets the version tag of the [`DividerLayout`].
+    /// Bump it whenever a release adds or removes containers.
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets whether each container is collapsed.
+    pub fn collapsed(mut self, collapsed: Vec<bool>) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Brings a loaded [`DividerLayout`] up to the current version.
+    ///
+    /// The migrate hook is only called when the saved version is older than
+    /// the current one. If the number of widths still doesn't match count
+    /// afterwards, the widths are redistributed proportionally so the
+    /// dividers stay aligned with the containers.
+    ///
+    /// A layout saved by a newer version is returned untouched, so saving it
+    /// again doesn't lose what the newer version stored. Check its version
+    /// before using it.
+    pub fn migrate(
+        self,
+        version: u32,
+        count: usize,
+        migrate: impl Fn(DividerLayout) -> DividerLayout,
+    ) -> DividerLayout {
+        if self.version > version {
+            return self;
+        }
+        let mut layout = if self.version < version {
+            migrate(self)
+        } else {
+            self
+        };
+
+        if layout.widths.len() != count {
+            layout.widths = redistribute(&layout.widths, count);
+        }
+        layout.version = version;
+        layout
+    }
+
+    /// Serializes the [`DividerLayout`] into a TOML document.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    /// Deserializes a [`DividerLayout`] from a TOML document.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(document: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(document)
+    }
+}
+
+/// The layouts of several dividers captured together under their names,
+/// like the workspaces of an application.
+///
+/// ```
+/// # use iced_divider::persistence::{DividerLayout, LayoutSnapshot};
+/// let mut sidebar = DividerLayout::new(vec![200.0, 600.0]);
+/// let mut tracks = DividerLayout::new(vec![80.0, 120.0]).collapsed(vec![false, true]);
+///
+/// let workspace = LayoutSnapshot::capture([("sidebar", &sidebar), ("tracks", &tracks)]);
+///
+/// sidebar.widths = vec![400.0, 400.0];
+/// workspace.apply([("sidebar", &mut sidebar), ("tracks", &mut tracks)]);
+///
+/// assert_eq!(sidebar.widths, vec![200.0, 600.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LayoutSnapshot {
+    /// The layouts of the dividers by name.
+    pub dividers: BTreeMap<String, DividerLayout>,
+}
+
+impl LayoutSnapshot {
+    /// Captures the layouts of the dividers under their names.
+    pub fn capture<'a>(
+        layouts: impl IntoIterator<Item = (impl Into<String>, &'a DividerLayout)>,
+    ) -> Self {
+        Self {
+            dividers: layouts.into_iter()
+                .map(|(name, layout)| (name.into(), layout.clone()))
+                .collect(),
+        }
+    }
+
+    /// Applies the captured layouts to the ones with the same names.
+    ///
+    /// A layout missing from the snapshot is left as it is. The captured
+    /// widths are redistributed when the number of containers changed since,
+    /// and collapsed flags that don't fit are not applied.
+    pub fn apply<'a>(&self, layouts: impl IntoIterator<Item = (&'a str, &'a mut DividerLayout)>) {
+        for (name, layout) in layouts {
+            let Some(captured) = self.dividers.get(name) else {
+                continue;
+            };
+            let count = layout.widths.len();
+
+            layout.widths = if captured.widths.len() == count {
+                captured.widths.clone()
+            } else {
+                redistribute(&captured.widths, count)
+            };
+            if captured.collapsed.is_empty() || captured.collapsed.len() == count {
+                layout.collapsed = captured.collapsed.clone();
+            }
+        }
+    }
+}
+
+/// Resizes widths to count items while keeping the total unchanged.
+///
+/// Existing items keep their relative sizes, added items start at the average
+/// width and removed items give their space back to the remaining ones.
+pub fn redistribute(widths: &[f32], count: usize) -> Vec<f32> {
+    if count == 0 {
+        return vec![];
+    }
+    let total: f32 = widths.iter().sum();
+    if widths.is_empty() || total <= 0.0 {
+        return vec![total.max(0.0) / count as f32; count];
+    }
+
+    let average = total / widths.len() as f32;
+    let mut new_widths: Vec<f32> = widths.iter().copied().take(count).collect();
+    new_widths.resize(count, average);
+
+    let new_total: f32 = new_widths.iter().sum();
+    new_widths.iter().map(|width| width * total / new_total).collect()
+}
+
+#[test]
+fn test_migrate() {
+    let saved = DividerLayout::new(vec![100.0, 200.0, 300.0]).version(1);
+
+    // A column was added in version 2, the hook inserts it up front.
+    let migrated = saved.clone().migrate(2, 4, |mut layout| {
+        layout.widths.insert(0, 50.0);
+        layout
+    });
+    assert_eq!(migrated, DividerLayout::new(vec![50.0, 100.0, 200.0, 300.0]).version(2));
+
+    // Same version but a missing column, widths are redistributed.
+    let redistributed = saved.migrate(1, 2, |layout| layout);
+    assert_eq!(redistributed.widths, vec![200.0, 400.0]);
+    assert_eq!(redistribute(&[100.0, 100.0], 4), vec![50.0; 4]);
+
+    // A newer version is left as it is, even with another count.
+    let newer = DividerLayout::new(vec![100.0, 200.0, 300.0]).version(3);
+    assert_eq!(newer.clone().migrate(2, 2, |layout| layout), newer);
+}
+
+#[test]
+fn test_snapshot() {
+    let mut editor = DividerLayout::new(vec![200.0, 600.0, 200.0]);
+    let workspace = LayoutSnapshot::capture([(
+        "editor",
+        &DividerLayout::new(vec![100.0, 800.0]).collapsed(vec![true, false]),
+    )]);
+
+    // a container was added since, the collapsed flags no longer fit
+    workspace.apply([("editor", &mut editor)]);
+    assert_eq!(editor.widths.len(), 3);
+    assert!(editor.collapsed.is_empty());
+
+    let mut editor = DividerLayout::new(vec![450.0, 450.0]);
+    workspace.apply([("editor", &mut editor)]);
+    assert_eq!(editor, workspace.dividers["editor"]);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_toml_round_trip() {
+    let layout = DividerLayout::new(vec![100.0, 150.5, 200.0]);
+
+    let document = layout.to_toml().unwrap();
+
+    assert_eq!(document, "version = 0\nwidths = [100.0, 150.5, 200.0]\n");
+    assert_eq!(DividerLayout::from_toml(&document).unwrap(), layout);
+}
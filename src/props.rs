@@ -0,0 +1,134 @@
+//! Hashable configuration of a [`Divider`], to skip rebuilding it with `lazy`.
+//!
+//! ```no_run
+//! # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)) }
+//! # let widths = vec![100.0, 200.0, 150.0];
+//! use iced::widget::lazy;
+//! use iced_divider::props::DividerProps;
+//!
+//! let props = DividerProps::horizontal(widths, 4.0, 21.0);
+//!
+//! let view: iced::Element<Message> = lazy(props, |props| {
+//!     props.divider(Message::Resized)
+//! })
+//! .into();
+//! ```
+use std::hash::{Hash, Hasher};
+
+use crate::divider::{divider_horizontal, divider_vertical, Catalog, Direction, Divider};
+
+/// The widths or heights, the dimensions of the handles and the direction
+/// of a [`Divider`], compared and hashed by the bits of the values.
+#[derive(Debug, Clone)]
+pub struct DividerProps {
+    /// The widths or heights of the containers.
+    pub widths: Vec<f32>,
+    /// The width of the handles.
+    pub handle_width: f32,
+    /// The height of the handles.
+    pub handle_height: f32,
+    /// The direction of the [`Divider`].
+    pub direction: Direction,
+    /// Whether the last handle is included.
+    pub include_last_handle: bool,
+}
+
+impl DividerProps {
+    /// Creates the props of a horizontal [`Divider`].
+    pub fn horizontal(widths: Vec<f32>, handle_width: f32, handle_height: f32) -> Self {
+        DividerProps {
+            widths,
+            handle_width,
+            handle_height,
+            direction: Direction::Horizontal,
+            include_last_handle: true,
+        }
+    }
+
+    /// Creates the props of a vertical [`Divider`].
+    pub fn vertical(heights: Vec<f32>, handle_width: f32, handle_height: f32) -> Self {
+        DividerProps {
+            direction: Direction::Vertical,
+            ..Self::horizontal(heights, handle_width, handle_height)
+        }
+    }
+
+    /// Sets whether the last handle is included.
+    pub fn include_last_handle(mut self, include: bool) -> Self {
+        self.include_last_handle = include;
+        self
+    }
+
+    /// Creates the [`Divider`] described by the props.
+    pub fn divider<'a, Message, Theme, Renderer, F>(
+        &self,
+        on_change: F,
+    ) -> Divider<'a, Message, Theme, Renderer, F>
+    where
+        Message: Clone,
+        Theme: Catalog + 'a,
+        F: Fn((usize, f32)) -> Message + 'a,
+    {
+        let divider = match self.direction {
+            Direction::Horizontal => divider_horizontal(
+                self.widths.clone(),
+                self.handle_width,
+                self.handle_height,
+                on_change),
+            Direction::Vertical => divider_vertical(
+                self.widths.clone(),
+                self.handle_width,
+                self.handle_height,
+                on_change),
+        };
+
+        divider.include_last_handle(self.include_last_handle)
+    }
+}
+
+impl PartialEq for DividerProps {
+    fn eq(&self, other: &Self) -> bool {
+        let bits = |values: &[f32]| {
+            values.iter().map(|value| value.to_bits()).collect::<Vec<_>>()
+        };
+
+        bits(&self.widths) == bits(&other.widths)
+            && self.handle_width.to_bits() == other.handle_width.to_bits()
+            && self.handle_height.to_bits() == other.handle_height.to_bits()
+            && self.direction == other.direction
+            && self.include_last_handle == other.include_last_handle
+    }
+}
+
+impl Hash for DividerProps {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for width in &self.widths {
+            width.to_bits().hash(state);
+        }
+        self.handle_width.to_bits().hash(state);
+        self.handle_height.to_bits().hash(state);
+        self.direction.hash(state);
+        self.include_last_handle.hash(state);
+    }
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::hash_map::DefaultHasher;
+
+    let hash = |props: &DividerProps| {
+        let mut hasher = DefaultHasher::new();
+        props.hash(&mut hasher);
+        hasher.finish()
+    };
+    let props = DividerProps::horizontal(vec![100.0, 200.0], 4.0, 21.0);
+
+    assert_eq!(hash(&props), hash(&props.clone()));
+    assert_ne!(hash(&props), hash(&DividerProps::vertical(vec![100.0, 200.0], 4.0, 21.0)));
+
+    // equal props hash the same, so 0.0 and -0.0 are not equal
+    let negative = DividerProps::horizontal(vec![-0.0, 200.0], 4.0, 21.0);
+    let positive = DividerProps::horizontal(vec![0.0, 200.0], 4.0, 21.0);
+    assert_ne!(positive, negative);
+    assert_eq!(positive, positive.clone());
+}
@@ -0,0 +1,375 @@
+//! Apply the changes published by a [`Divider`](crate::divider::Divider)
+//! to the widths or heights held by the application.
+
+/// Applies a change the same way as the examples: the container before the
+/// handle takes the new value and the one after it absorbs the difference.
+/// Moving the last handle changes the total.
+pub fn apply(widths: &mut [f32], (index, value): (usize, f32)) {
+    let diff = widths[index] - value;
+
+    // Adjust the left side
+    widths[index] = value;
+
+    // Adjust the right side
+    if index < widths.len()-1 {
+        widths[index+1] += diff;
+    }
+}
+
+/// Applies the changes of a single input published together, like the ones
+/// of [`Divider::on_changes`](crate::divider::Divider::on_changes), in order.
+pub fn apply_all(widths: &mut [f32], changes: &[(usize, f32)]) {
+    for change in changes {
+        apply(widths, *change);
+    }
+}
+
+/// Applies a change while keeping the ratio between the two locked containers constant.
+///
+/// When a handle resizes one of the locked containers the other one follows and
+/// the absorber takes up the difference. A handle between the two locked containers
+/// can't move, and changes that would make the absorber negative are ignored.
+pub fn apply_locked(
+    widths: &mut [f32],
+    change: (usize, f32),
+    locked: (usize, usize),
+    absorber: usize,
+) {
+    let (a, b) = locked;
+    if widths[b] <= 0.0 {
+        apply(widths, change);
+        return;
+    }
+    let ratio = widths[a] / widths[b];
+
+    let mut new_widths = widths.to_vec();
+    apply(&mut new_widths, change);
+    let total: f32 = new_widths.iter().sum();
+
+    let a_changed = new_widths[a] != widths[a];
+    let b_changed = new_widths[b] != widths[b];
+
+    match (a_changed, b_changed) {
+        (true, true) => return,
+        (true, false) => new_widths[b] = new_widths[a] / ratio,
+        (false, true) => new_widths[a] = new_widths[b] * ratio,
+        (false, false) => {},
+    }
+
+    let others: f32 = new_widths.iter()
+        .enumerate()
+        .filter(|(i, _)| *i != absorber)
+        .map(|(_, width)| width)
+        .sum();
+
+    if total - others < 0.0 {
+        return;
+    }
+    new_widths[absorber] = total - others;
+    widths.copy_from_slice(&new_widths);
+}
+
+/// Applies a change while sparing the sticky containers.
+///
+/// The container growing from the drag takes its space from the containers
+/// beyond the handle, nearest first, down to the minimum. Sticky containers
+/// only shrink once the others are at the minimum. Moving the last handle
+/// changes the total.
+pub fn apply_sticky(
+    widths: &mut [f32],
+    (index, value): (usize, f32),
+    sticky: &[usize],
+    min: f32,
+) {
+    if index >= widths.len()-1 {
+        apply(widths, (index, value));
+        return;
+    }
+    let diff = value - widths[index];
+
+    let (growing, shrinking): (usize, Vec<usize>) = if diff >= 0.0 {
+        (index, (index+1..widths.len()).collect())
+    } else {
+        (index+1, (0..=index).rev().collect())
+    };
+
+    let order = shrinking.iter()
+        .filter(|i| !sticky.contains(i))
+        .chain(shrinking.iter().filter(|i| sticky.contains(i)));
+
+    let mut remaining = diff.abs();
+    for &i in order {
+        let taken = (widths[i] - min).max(0.0).min(remaining);
+        widths[i] -= taken;
+        remaining -= taken;
+    }
+    widths[growing] += diff.abs() - remaining;
+}
+
+/// Scales the widths or heights proportionally from the old size of the
+/// window to the new one, call it from the handler of `window::Event::Resized`.
+pub fn rescale_on_resize(widths: &mut [f32], old: f32, new: f32) {
+    if old <= 0.0 || new <= 0.0 {
+        return;
+    }
+    let scale = new / old;
+
+    for width in widths.iter_mut() {
+        *width *= scale;
+    }
+}
+
+/// Computes the widths or heights sharing the total by the weights,
+/// 1:2:1 gives a quarter, a half and a quarter. Weights summing to zero
+/// share the total equally.
+pub fn from_weights(weights: &[f32], total: f32) -> Vec<f32> {
+    let sum: f32 = weights.iter().sum();
+    if sum <= 0.0 {
+        return vec![total / weights.len().max(1) as f32; weights.len()];
+    }
+    weights.iter().map(|weight| total * weight / sum).collect()
+}
+
+/// How the total is shared again when containers are added or removed,
+/// see [`redistribute`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Redistribution {
+    /// All the containers are scaled to the total.
+    #[default]
+    Proportional,
+    /// The last container takes the difference.
+    FromLast,
+    /// All the containers get an equal share.
+    Equal,
+}
+
+/// Shares the previous total between the widths after containers were
+/// added or removed. The last container doesn't shrink below zero.
+pub fn redistribute(widths: &[f32], total: f32, redistribution: Redistribution) -> Vec<f32> {
+    let mut widths = widths.to_vec();
+    if widths.is_empty() {
+        return widths;
+    }
+    let sum: f32 = widths.iter().sum();
+
+    match redistribution {
+        Redistribution::Proportional if sum > 0.0 => {
+            for width in widths.iter_mut() {
+                *width *= total / sum;
+            }
+        },
+        Redistribution::FromLast => {
+            let last = widths.len()-1;
+            widths[last] = (widths[last] + total - sum).max(0.0);
+        },
+        Redistribution::Proportional | Redistribution::Equal => {
+            let count = widths.len() as f32;
+            widths.fill(total / count);
+        },
+    }
+    widths
+}
+
+/// Which containers follow a change of the size of the window,
+/// see [`resize_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// The first containers keep their size, the last one takes the change.
+    Start,
+    /// The last containers keep their size, the first one takes the change.
+    End,
+    /// All the containers keep their ratio, see [`rescale_on_resize`].
+    #[default]
+    Relative,
+}
+
+/// Applies the change of the size of the window from old to new following
+/// the strategy, call it from the handler of `window::Event::Resized`.
+/// The container taking the change doesn't shrink below zero.
+pub fn resize_with(widths: &mut [f32], old: f32, new: f32, strategy: Strategy) {
+    let absorber = match strategy {
+        Strategy::Start => widths.len().checked_sub(1),
+        Strategy::End => (!widths.is_empty()).then_some(0),
+        Strategy::Relative => {
+            rescale_on_resize(widths, old, new);
+            return;
+        },
+    };
+    if let Some(absorber) = absorber {
+        widths[absorber] = (widths[absorber] + new - old).max(0.0);
+    }
+}
+
+/// Takes the amount from the containers in order of priority, the lowest
+/// first and the last one first among equal priorities, each down to zero.
+/// Containers without a priority have the lowest one. Returns the part of
+/// the amount the containers couldn't give.
+pub fn shrink_by_priority(widths: &mut [f32], amount: f32, priority: &[u8]) -> f32 {
+    let priority_of = |i: usize| priority.get(i).copied().unwrap_or(0);
+
+    let mut order: Vec<usize> = (0..widths.len()).rev().collect();
+    order.sort_by_key(|i| priority_of(*i));
+
+    let mut remaining = amount.max(0.0);
+    for i in order {
+        let taken = widths[i].max(0.0).min(remaining);
+        widths[i] -= taken;
+        remaining -= taken;
+    }
+    remaining
+}
+
+/// Applies the change of the size of the window from old to new by priority,
+/// like an editor between tool panels that shrinks last. A smaller window
+/// shrinks the containers as [`shrink_by_priority`], a larger one grows the
+/// container with the highest priority, the first one among equals.
+pub fn resize_by_priority(widths: &mut [f32], old: f32, new: f32, priority: &[u8]) {
+    if new < old {
+        shrink_by_priority(widths, old - new, priority);
+        return;
+    }
+    let priority_of = |i: usize| priority.get(i).copied().unwrap_or(0);
+
+    let highest = (0..widths.len()).rev().max_by_key(|i| priority_of(*i));
+    if let Some(highest) = highest {
+        widths[highest] += new - old;
+    }
+}
+
+/// Keeps the resized container and the one after it at least at their
+/// minimum sizes, containers without one have no minimum.
+pub(crate) fn keep_min_sizes(
+    sizes: &[f32],
+    min_sizes: &[f32],
+    (index, value): (usize, f32),
+) -> (usize, f32) {
+    let min_size = |i: usize| min_sizes.get(i).copied().unwrap_or(0.0);
+    let max = match sizes.get(index+1) {
+        Some(next) => sizes[index] + next - min_size(index+1),
+        None => f32::INFINITY,
+    };
+
+    (index, value.min(max).max(min_size(index)))
+}
+
+#[test]
+fn test_apply_all() {
+    // a mirrored drag, the paired handle moved back once the first is applied
+    let mut widths = vec![100.0, 400.0, 100.0];
+
+    apply_all(&mut widths, &[(0, 150.0), (1, 300.0)]);
+    assert_eq!(widths, vec![150.0, 300.0, 150.0]);
+}
+
+#[test]
+fn test_apply_locked() {
+    // two equal previews and a side panel absorbing the change
+    let mut widths = vec![200.0, 200.0, 100.0];
+
+    apply_locked(&mut widths, (1, 180.0), (0, 1), 2);
+    assert_eq!(widths, vec![180.0, 180.0, 140.0]);
+
+    // the handle between the previews is locked
+    apply_locked(&mut widths, (0, 100.0), (0, 1), 2);
+    assert_eq!(widths, vec![180.0, 180.0, 140.0]);
+
+    // the panel can't become negative
+    apply_locked(&mut widths, (1, 300.0), (0, 1), 2);
+    assert_eq!(widths, vec![180.0, 180.0, 140.0]);
+}
+
+#[test]
+fn test_apply_sticky() {
+    // an editor between two tool panels
+    let mut widths = vec![100.0, 400.0, 100.0];
+
+    // growing the first panel takes from the last one before the editor
+    apply_sticky(&mut widths, (0, 150.0), &[1], 50.0);
+    assert_eq!(widths, vec![150.0, 400.0, 50.0]);
+
+    // the editor shrinks once the panel is at the minimum
+    apply_sticky(&mut widths, (0, 200.0), &[1], 50.0);
+    assert_eq!(widths, vec![200.0, 350.0, 50.0]);
+
+    // shrinking the editor by its handle takes from the first panel
+    apply_sticky(&mut widths, (1, 300.0), &[1], 50.0);
+    assert_eq!(widths, vec![150.0, 350.0, 100.0]);
+}
+
+#[test]
+fn test_rescale_on_resize() {
+    let mut widths = vec![100.0, 200.0, 100.0];
+
+    rescale_on_resize(&mut widths, 800.0, 1200.0);
+    assert_eq!(widths, vec![150.0, 300.0, 150.0]);
+
+    // a minimized window keeps the widths
+    rescale_on_resize(&mut widths, 1200.0, 0.0);
+    assert_eq!(widths, vec![150.0, 300.0, 150.0]);
+}
+
+#[test]
+fn test_resize_with() {
+    let mut widths = vec![200.0, 600.0];
+
+    resize_with(&mut widths, 800.0, 1000.0, Strategy::Start);
+    assert_eq!(widths, vec![200.0, 800.0]);
+
+    resize_with(&mut widths, 1000.0, 900.0, Strategy::End);
+    assert_eq!(widths, vec![100.0, 800.0]);
+
+    resize_with(&mut widths, 900.0, 450.0, Strategy::Relative);
+    assert_eq!(widths, vec![50.0, 400.0]);
+}
+
+#[test]
+fn test_from_weights() {
+    assert_eq!(from_weights(&[1.0, 2.0, 1.0], 800.0), vec![200.0, 400.0, 200.0]);
+    assert_eq!(from_weights(&[0.0, 0.0], 300.0), vec![150.0, 150.0]);
+    assert!(from_weights(&[], 300.0).is_empty());
+}
+
+#[test]
+fn test_redistribute() {
+    // a pane of 200.0 added to a total of 600.0
+    let widths = [100.0, 500.0, 200.0];
+
+    assert_eq!(redistribute(&widths, 600.0, Redistribution::Proportional), vec![75.0, 375.0, 150.0]);
+    assert_eq!(redistribute(&widths, 600.0, Redistribution::FromLast), vec![100.0, 500.0, 0.0]);
+    assert_eq!(redistribute(&widths, 600.0, Redistribution::Equal), vec![200.0, 200.0, 200.0]);
+}
+
+#[test]
+fn test_shrink_by_priority() {
+    // an editor between two tool panels
+    let mut widths = vec![100.0, 400.0, 100.0];
+
+    assert_eq!(shrink_by_priority(&mut widths, 150.0, &[0, 1, 0]), 0.0);
+    assert_eq!(widths, vec![50.0, 400.0, 0.0]);
+
+    assert_eq!(shrink_by_priority(&mut widths, 500.0, &[0, 1, 0]), 50.0);
+    assert_eq!(widths, vec![0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_resize_by_priority() {
+    let mut widths = vec![100.0, 400.0, 100.0];
+
+    resize_by_priority(&mut widths, 600.0, 500.0, &[0, 1, 0]);
+    assert_eq!(widths, vec![100.0, 400.0, 0.0]);
+
+    // the editor takes the growth
+    resize_by_priority(&mut widths, 500.0, 700.0, &[0, 1, 0]);
+    assert_eq!(widths, vec![100.0, 600.0, 0.0]);
+}
+
+#[test]
+fn test_keep_min_sizes() {
+    let sizes = [80.0, 120.0, 80.0];
+    let min_sizes = [40.0, 60.0];
+
+    assert_eq!(keep_min_sizes(&sizes, &min_sizes, (0, 20.0)), (0, 40.0));
+    assert_eq!(keep_min_sizes(&sizes, &min_sizes, (0, 180.0)), (0, 140.0));
+    // the last container has no minimum
+    assert_eq!(keep_min_sizes(&sizes, &min_sizes, (1, 200.0)), (1, 200.0));
+}
@@ -0,0 +1,171 @@
+//! Two scrollables side by side or stacked, with a handle to resize them.
+//!
+//! ```no_run
+//! # use iced::widget::{column, text};
+//! # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)) }
+//! # let sizes = [200.0, 400.0];
+//! use iced_divider::split_scroll::split_scroll;
+//!
+//! let view: iced::Element<Message> = split_scroll(
+//!         column((0..100).map(|i| text(i.to_string()).into())),
+//!         text("details"),
+//!         sizes)
+//!     .breadth(500.0)
+//!     .on_resize(Message::Resized)
+//!     .into();
+//! ```
+use iced::widget::{column, container, row, scrollable};
+use iced::{Element, Length};
+
+use crate::divider::{divider_horizontal, divider_vertical, Direction, Role};
+use crate::resize::{apply_all, Strategy};
+
+/// Creates a [`SplitScroll`] with the two contents and their sizes.
+pub fn split_scroll<'a, Message>(
+    first: impl Into<Element<'a, Message>>,
+    second: impl Into<Element<'a, Message>>,
+    sizes: [f32; 2],
+) -> SplitScroll<'a, Message> {
+    SplitScroll::new(first, second, sizes)
+}
+
+/// Two scrollables separated by the handle of a divider, like a master
+/// and detail view.
+///
+/// The handle is drawn over the scrollables, so it stays in place
+/// whatever they are scrolled to.
+///
+/// With a [`SplitScroll::strategy`], the split fills its parent and keeps
+/// the first, the second or their ratio when the parent is resized.
+#[allow(missing_debug_implementations)]
+pub struct SplitScroll<'a, Message> {
+    first: Element<'a, Message>,
+    second: Element<'a, Message>,
+    sizes: [f32; 2],
+    on_resize: Option<Box<dyn Fn((usize, f32)) -> Message + 'a>>,
+    breadth: f32,
+    handle_size: f32,
+    direction: Direction,
+    strategy: Option<(Strategy, Box<dyn Fn([f32; 2]) -> Message + 'a>)>,
+}
+
+impl<'a, Message> SplitScroll<'a, Message> {
+    /// The default size across the split.
+    pub const DEFAULT_BREADTH: f32 = 400.0;
+
+    /// Creates a new [`SplitScroll`].
+    pub fn new(
+        first: impl Into<Element<'a, Message>>,
+        second: impl Into<Element<'a, Message>>,
+        sizes: [f32; 2],
+    ) -> Self {
+        SplitScroll {
+            first: first.into(),
+            second: second.into(),
+            sizes,
+            on_resize: None,
+            breadth: Self::DEFAULT_BREADTH,
+            handle_size: 4.0,
+            direction: Direction::Horizontal,
+            strategy: None,
+        }
+    }
+
+    /// Sets the message produced when the handle is moved,
+    /// the same as the one of the divider.
+    pub fn on_resize(mut self, on_resize: impl Fn((usize, f32)) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the size across the split, the height of a side by side
+    /// split or the width of a stacked one.
+    pub fn breadth(mut self, breadth: f32) -> Self {
+        self.breadth = breadth;
+        self
+    }
+
+    /// Sets the thickness of the handle.
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    /// Sets the direction, [`Direction::Vertical`] stacks the scrollables.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Fills the parent along the split and applies the strategy to the sizes
+    /// when the parent is resized, producing the message of on_resized with
+    /// both new sizes.
+    pub fn strategy(
+        mut self,
+        strategy: Strategy,
+        on_resized: impl Fn([f32; 2]) -> Message + 'a,
+    ) -> Self {
+        self.strategy = Some((strategy, Box::new(on_resized)));
+        self
+    }
+}
+
+impl<'a, Message> From<SplitScroll<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(split: SplitScroll<'a, Message>) -> Self {
+        let SplitScroll {
+            first,
+            second,
+            sizes,
+            on_resize,
+            breadth,
+            handle_size,
+            direction,
+            strategy,
+        } = split;
+
+        // the split measures its parent to follow its size
+        let extent = if strategy.is_some() { Length::Fill } else { Length::Shrink };
+
+        let content: Element<'a, Message> = match direction {
+            Direction::Horizontal => row![
+                container(scrollable(first)).width(sizes[0]).height(breadth),
+                container(scrollable(second)).width(sizes[1]).height(breadth),
+            ].width(extent).into(),
+            Direction::Vertical => column![
+                container(scrollable(first)).width(breadth).height(sizes[0]),
+                container(scrollable(second)).width(breadth).height(sizes[1]),
+            ].height(extent).into(),
+        };
+
+        let Some(on_resize) = on_resize else {
+            return content;
+        };
+
+        let divider = match direction {
+            Direction::Horizontal =>
+                divider_horizontal(sizes.to_vec(), handle_size, breadth, on_resize),
+            Direction::Vertical =>
+                divider_vertical(sizes.to_vec(), breadth, handle_size, on_resize),
+        };
+
+        let divider = divider
+            .include_last_handle(false)
+            .role(Role::Panel)
+            .content(content);
+
+        match strategy {
+            Some((strategy, on_resized)) => divider
+                .resize_strategy(strategy)
+                .on_changes(move |changes| {
+                    let mut sizes = sizes;
+                    apply_all(&mut sizes, &changes);
+                    on_resized(sizes)
+                })
+                .into(),
+            None => divider.into(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! A table with a resizable header and rows sharing the column widths.
+//!
+//! ```no_run
+//! # use iced::widget::text;
+//! # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)), Sort(usize) }
+//! # let widths = vec![100.0, 200.0, 150.0];
+//! use iced_divider::table::table;
+//!
+//! let files = [("a.txt", "1 kB", "today"), ("b.txt", "2 kB", "yesterday")];
+//!
+//! let view: iced::Element<Message> = table(vec!["Name", "Size", "Date"], widths)
+//!     .rows(files.iter().map(|(name, size, date)| [text(*name), text(*size), text(*date)]))
+//!     .on_resize(Message::Resized)
+//!     .on_sort(Message::Sort)
+//!     .into();
+//! ```
+use iced::widget::{column, container, row};
+use iced::Element;
+
+use crate::table_header::{table_header, SortOrder, TableHeader};
+
+/// Creates a [`Table`] with the given titles and column widths.
+pub fn table<'a, Message>(
+    titles: Vec<impl Into<String>>,
+    widths: Vec<f32>,
+) -> Table<'a, Message> {
+    Table::new(titles, widths)
+}
+
+/// A [`TableHeader`] above rows of cells, both laid out with the same
+/// widths, so the handles of the header always line up with the columns.
+///
+/// The message of [`Table::on_resize`] is the same as the one of the
+/// header, apply it to the widths given to the [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message> {
+    header: TableHeader<'a, Message>,
+    widths: Vec<f32>,
+    rows: Vec<Vec<Element<'a, Message>>>,
+    row_height: Option<f32>,
+}
+
+impl<'a, Message> Table<'a, Message> {
+    /// Creates a new [`Table`].
+    pub fn new(titles: Vec<impl Into<String>>, widths: Vec<f32>) -> Self {
+        Table {
+            header: table_header(titles, widths.clone()),
+            widths,
+            rows: vec![],
+            row_height: None,
+        }
+    }
+
+    /// Sets the rows of the [`Table`], each with a cell for each column.
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = impl IntoIterator<Item = impl Into<Element<'a, Message>>>>,
+    ) -> Self {
+        self.rows = rows.into_iter()
+            .map(|cells| cells.into_iter().map(Into::into).collect())
+            .collect();
+        self
+    }
+
+    /// Sets the message produced when a column is resized.
+    pub fn on_resize(mut self, on_resize: impl Fn((usize, f32)) -> Message + 'a) -> Self {
+        self.header = self.header.on_resize(on_resize);
+        self
+    }
+
+    /// Sets the message produced when a title is clicked.
+    pub fn on_sort(mut self, on_sort: impl Fn(usize) -> Message + 'a) -> Self {
+        self.header = self.header.on_sort(on_sort);
+        self
+    }
+
+    /// Marks the column the table is sorted by.
+    pub fn sorted(mut self, index: usize, order: SortOrder) -> Self {
+        self.header = self.header.sorted(index, order);
+        self
+    }
+
+    /// Sets the width no column can be resized below.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.header = self.header.min_width(min_width);
+        self
+    }
+
+    /// Sets the height of the header.
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.header = self.header.height(height);
+        self
+    }
+
+    /// Sets the height of the rows, by default the height of their content.
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+}
+
+impl<'a, Message> From<Table<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(table: Table<'a, Message>) -> Self {
+        let Table {
+            header,
+            widths,
+            rows,
+            row_height,
+        } = table;
+
+        let rows = rows.into_iter().map(|cells| {
+            let cells = cells.into_iter()
+                .zip(widths.iter())
+                .map(|(content, width)| {
+                    let cell = container(content).width(*width).clip(true);
+                    match row_height {
+                        Some(row_height) => cell.height(row_height).into(),
+                        None => cell.into(),
+                    }
+                });
+
+            row(cells).into()
+        });
+
+        column![header, column(rows)].into()
+    }
+}
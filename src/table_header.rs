@@ -0,0 +1,138 @@
+//! A table header with resizable and sortable columns.
+//!
+//! ```no_run
+//! # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)), Sort(usize) }
+//! # let widths = vec![100.0, 200.0, 150.0];
+//! use iced_divider::table_header::{table_header, SortOrder};
+//!
+//! let header: iced::Element<Message> = table_header(vec!["Name", "Size", "Date"], widths)
+//!     .sorted(1, SortOrder::Descending)
+//!     .on_resize(Message::Resized)
+//!     .on_sort(Message::Sort)
+//!     .into();
+//! ```
+use iced::widget::{button, text};
+use iced::{alignment, Element, Length};
+
+use crate::columns::columns;
+
+/// Creates a [`TableHeader`] with the given titles and column widths.
+pub fn table_header<'a, Message>(
+    titles: Vec<impl Into<String>>,
+    widths: Vec<f32>,
+) -> TableHeader<'a, Message> {
+    TableHeader::new(titles, widths)
+}
+
+/// The order of a sorted column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest first
+    Ascending,
+    /// Largest first
+    Descending,
+}
+
+/// A row of column titles with handles to resize the columns.
+///
+/// Clicking a title produces the message of [`TableHeader::on_sort`]
+/// with the index of the column.
+#[allow(missing_debug_implementations)]
+pub struct TableHeader<'a, Message> {
+    titles: Vec<String>,
+    widths: Vec<f32>,
+    on_resize: Option<Box<dyn Fn((usize, f32)) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    sorted: Option<(usize, SortOrder)>,
+    min_width: f32,
+    height: f32,
+}
+
+impl<'a, Message> TableHeader<'a, Message> {
+    /// The default height of a [`TableHeader`].
+    pub const DEFAULT_HEIGHT: f32 = 30.0;
+
+    /// Creates a new [`TableHeader`].
+    pub fn new(titles: Vec<impl Into<String>>, widths: Vec<f32>) -> Self {
+        TableHeader {
+            titles: titles.into_iter().map(Into::into).collect(),
+            widths,
+            on_resize: None,
+            on_sort: None,
+            sorted: None,
+            min_width: 0.0,
+            height: Self::DEFAULT_HEIGHT,
+        }
+    }
+
+    /// Sets the message produced when a column is resized.
+    pub fn on_resize(mut self, on_resize: impl Fn((usize, f32)) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message produced when a title is clicked.
+    pub fn on_sort(mut self, on_sort: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Marks the column the table is sorted by.
+    pub fn sorted(mut self, index: usize, order: SortOrder) -> Self {
+        self.sorted = Some((index, order));
+        self
+    }
+
+    /// Sets the width no column can be resized below.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the height of the [`TableHeader`].
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, Message> From<TableHeader<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(header: TableHeader<'a, Message>) -> Self {
+        let TableHeader {
+            titles,
+            widths,
+            on_resize,
+            on_sort,
+            sorted,
+            min_width,
+            height,
+        } = header;
+
+        let titles = titles.into_iter().enumerate().map(|(i, title)| {
+            let title = match sorted {
+                Some((index, SortOrder::Ascending)) if index == i => format!("{title} ▲"),
+                Some((index, SortOrder::Descending)) if index == i => format!("{title} ▼"),
+                _ => title,
+            };
+
+            button(text(title).align_y(alignment::Vertical::Center))
+                .on_press_maybe(on_sort.as_ref().map(|on_sort| on_sort(i)))
+                .width(Length::Fill)
+                .height(height)
+                .style(button::secondary)
+        });
+
+        let header = columns(widths)
+            .children(titles)
+            .min_width(min_width)
+            .handle_height(height);
+
+        match on_resize {
+            Some(on_resize) => header.on_resize(on_resize).into(),
+            None => header.into(),
+        }
+    }
+}
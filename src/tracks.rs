@@ -0,0 +1,215 @@
+//! Stacked tracks with handles to resize their heights, like the lanes
+//! of an audio or timeline editor.
+//!
+//! ```no_run
+//! # use iced::widget::text;
+//! # #[derive(Debug, Clone)] enum Message { Resized((usize, f32)) }
+//! # let heights = vec![80.0, 120.0, 80.0];
+//! use iced_divider::tracks::tracks;
+//!
+//! let lanes: iced::Element<Message> = tracks(heights)
+//!     .children([text("drums"), text("bass"), text("keys")])
+//!     .min_heights(vec![40.0, 60.0, 40.0])
+//!     .collapsed(vec![2])
+//!     .on_resize(Message::Resized)
+//!     .into();
+//! ```
+use iced::widget::{column, container};
+use iced::Element;
+
+use crate::divider::divider_vertical;
+use crate::persistence::DividerLayout;
+use crate::resize::keep_min_sizes;
+
+/// Creates [`Tracks`] with the given heights.
+pub fn tracks<'a, Message>(heights: Vec<f32>) -> Tracks<'a, Message> {
+    Tracks::new(heights)
+}
+
+/// A column of tracks with a divider to resize them.
+///
+/// The message of [`Tracks::on_resize`] is the same as the one of the
+/// divider, the index of the track and its new height.
+#[allow(missing_debug_implementations)]
+pub struct Tracks<'a, Message> {
+    heights: Vec<f32>,
+    children: Vec<Element<'a, Message>>,
+    on_resize: Option<Box<dyn Fn((usize, f32)) -> Message + 'a>>,
+    min_heights: Vec<f32>,
+    collapsed: Vec<usize>,
+    collapsed_height: f32,
+    compact: bool,
+    width: f32,
+    handle_height: f32,
+}
+
+impl<'a, Message> Tracks<'a, Message> {
+    /// The default width of the [`Tracks`].
+    pub const DEFAULT_WIDTH: f32 = 600.0;
+
+    /// Creates new [`Tracks`] with the given heights.
+    pub fn new(heights: Vec<f32>) -> Self {
+        Tracks {
+            heights,
+            children: vec![],
+            on_resize: None,
+            min_heights: vec![],
+            collapsed: vec![],
+            collapsed_height: 0.0,
+            compact: false,
+            width: Self::DEFAULT_WIDTH,
+            handle_height: 4.0,
+        }
+    }
+
+    /// Sets the contents of the tracks, one for each height.
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = impl Into<Element<'a, Message>>>,
+    ) -> Self {
+        self.children = children.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the message produced when a track is resized.
+    /// Without it, the tracks cannot be resized.
+    pub fn on_resize(mut self, on_resize: impl Fn((usize, f32)) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the height no track can be resized below, one for each track.
+    /// Tracks without one have no minimum.
+    pub fn min_heights(mut self, min_heights: Vec<f32>) -> Self {
+        self.min_heights = min_heights;
+        self
+    }
+
+    /// Sets the indices of the collapsed tracks, shown with the collapsed height.
+    pub fn collapsed(mut self, collapsed: Vec<usize>) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Sets the heights and the collapsed tracks from a saved [`DividerLayout`],
+    /// like one restored from a [`LayoutSnapshot`](crate::persistence::LayoutSnapshot).
+    pub fn layout(mut self, layout: &DividerLayout) -> Self {
+        self.heights.clone_from(&layout.widths);
+        self.collapsed = layout.collapsed.iter()
+            .enumerate()
+            .filter_map(|(i, is_collapsed)| is_collapsed.then_some(i))
+            .collect();
+        self
+    }
+
+    /// Sets the height of a collapsed track, room for a title for example.
+    pub fn collapsed_height(mut self, collapsed_height: f32) -> Self {
+        self.collapsed_height = collapsed_height;
+        self
+    }
+
+    /// Shows every track that isn't collapsed at its minimum height,
+    /// to fit many tracks on the screen.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the width of the tracks.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the handles.
+    pub fn handle_height(mut self, handle_height: f32) -> Self {
+        self.handle_height = handle_height;
+        self
+    }
+}
+
+impl<'a, Message> From<Tracks<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(tracks: Tracks<'a, Message>) -> Self {
+        let Tracks {
+            heights,
+            children,
+            on_resize,
+            min_heights,
+            collapsed,
+            collapsed_height,
+            compact,
+            width,
+            handle_height,
+        } = tracks;
+
+        let displayed: Vec<f32> = heights.iter().enumerate().map(|(i, height)| {
+            match (collapsed.contains(&i), compact, min_heights.get(i)) {
+                (true, _, _) => collapsed_height,
+                (false, true, Some(min_height)) => *min_height,
+                _ => *height,
+            }
+        })
+        .collect();
+
+        let lanes = children.into_iter()
+            .zip(displayed.iter())
+            .map(|(content, height)| container(content).width(width).height(*height).into());
+
+        let content = column(lanes);
+
+        let Some(on_resize) = on_resize else {
+            return content.into();
+        };
+
+        // the divider resizes the displayed heights, the application the stored ones
+        let shown = displayed.clone();
+
+        divider_vertical(
+            displayed,
+            width,
+            handle_height,
+            move |change| {
+                let change = to_stored(&heights, &shown, &collapsed, change);
+                on_resize(keep_min_sizes(&heights, &min_heights, change))
+            })
+            .content(content)
+            .into()
+    }
+}
+
+/// Maps a change of the displayed heights back to the stored heights, the
+/// tracks are resized by as much as on screen. A collapsed track, or the
+/// one above it, keeps its height.
+fn to_stored(
+    stored: &[f32],
+    displayed: &[f32],
+    collapsed: &[usize],
+    (index, value): (usize, f32),
+) -> (usize, f32) {
+    if collapsed.contains(&index) || collapsed.contains(&(index+1)) {
+        return (index, stored[index]);
+    }
+    (index, stored[index] + value - displayed[index])
+}
+
+#[test]
+fn test_to_stored() {
+    // the first track is shown at its minimum height in compact mode
+    let stored = [120.0, 200.0, 80.0];
+    let displayed = [40.0, 200.0, 30.0];
+
+    assert_eq!(to_stored(&stored, &displayed, &[2], (0, 60.0)), (0, 140.0));
+    assert_eq!(to_stored(&stored, &displayed, &[2], (1, 150.0)), (1, 200.0));
+}
+
+#[test]
+fn test_layout() {
+    let layout = DividerLayout::new(vec![80.0, 120.0, 80.0]).collapsed(vec![false, true, true]);
+    let tracks = tracks::<()>(vec![]).layout(&layout);
+
+    assert_eq!(tracks.heights, layout.widths);
+    assert_eq!(tracks.collapsed, vec![1, 2]);
+}